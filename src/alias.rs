@@ -1,5 +1,5 @@
 use std::{
-    collections::{HashMap, HashSet},
+    collections::{BTreeMap, HashMap, HashSet},
     fmt::Display,
     ops::{Deref, DerefMut},
     path::PathBuf,
@@ -7,6 +7,11 @@ use std::{
 
 use serde::{Deserialize, Serialize};
 
+use crate::{
+    clocks::Clock, history::RollLog, progress::ProgressTrack, resources::Resources,
+    session::Session, settings::Settings, stats::Stats,
+};
+
 const DIR_NAME: &str = ".disle";
 
 #[derive(Serialize, Deserialize)]
@@ -15,13 +20,38 @@ pub struct Data {
     pub global_aliases: HashMap<String, String>,
     // user id, map of aliases (alias, command)
     pub users_aliases: HashMap<u64, HashMap<String, String>>,
+    #[serde(default)]
+    pub settings: Settings,
+    // channel id, last rolls in the channel
+    #[serde(default)]
+    pub roll_logs: HashMap<u64, RollLog>,
+    // channel id, running or last session in the channel
+    #[serde(default)]
+    pub sessions: HashMap<u64, Session>,
+    #[serde(default)]
+    pub stats: Stats,
+    #[serde(default)]
+    pub resources: Resources,
+    // channel id, progress clocks by name
+    #[serde(default)]
+    pub clocks: HashMap<u64, BTreeMap<String, Clock>>,
+    // user id, Ironsworn progress tracks by name
+    #[serde(default)]
+    pub progress: HashMap<u64, BTreeMap<String, ProgressTrack>>,
 }
 
 impl Data {
-    fn new() -> Self {
+    pub(crate) fn new() -> Self {
         Self {
             global_aliases: HashMap::new(),
             users_aliases: HashMap::new(),
+            settings: Settings::default(),
+            roll_logs: HashMap::new(),
+            sessions: HashMap::new(),
+            stats: Stats::default(),
+            resources: Resources::default(),
+            clocks: HashMap::new(),
+            progress: HashMap::new(),
         }
     }
 }
@@ -111,7 +141,7 @@ impl Ord for SplitPart {
     }
 }
 
-impl<'a> Display for SplitPart {
+impl Display for SplitPart {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
             SplitPart::Alias(a) => write!(f, "{}", &a),
@@ -171,7 +201,9 @@ fn split_cmd(mut cmd: &str) -> Result<Vec<SplitPart>, String> {
 
     while let Some(start_alias) = cmd.find('$') {
         let end_alias = cmd[start_alias..]
-            .find(|c: char| c.is_whitespace() || c == '+' || c == '-' || c == '*' || c == '/')
+            .find(|c: char| {
+                c.is_whitespace() || c == '+' || c == '-' || c == '*' || c == '/' || c == ';'
+            })
             .or_else(|| Some(cmd.len() - start_alias))
             .unwrap()
             + start_alias;
@@ -504,10 +536,7 @@ impl AllData {
         match self.expand_alias(&command, chat_id, user_id, false) {
             Ok(_) => {
                 let data = self.entry(chat_id).or_insert_with(Data::new);
-                let user_aliases = data
-                    .users_aliases
-                    .entry(user_id)
-                    .or_insert_with(HashMap::new);
+                let user_aliases = data.users_aliases.entry(user_id).or_default();
                 let alias = alias.to_lowercase();
                 let msg = format!("Alias `${}` set for user {}", alias, user_name);
                 let msg = match alias.as_str() {
@@ -598,21 +627,43 @@ impl AllData {
         Ok(msg)
     }
 
-    pub fn load_alias_data(&mut self, chat_id: u64) -> std::io::Result<&'static str> {
+    fn read_alias_data(chat_id: u64) -> std::io::Result<Option<Data>> {
         let mut path = PathBuf::from(DIR_NAME);
         if path.exists() {
             path.push(format!("{}.ron", chat_id));
-            match std::fs::read_to_string(path) {
-                Ok(content) => {
-                    let data: Data = ron::de::from_str(&content).unwrap_or_else(|_| Data::new());
-                    self.insert(chat_id, data);
-                }
-                Err(e) => return Err(e),
-            }
+            let content = std::fs::read_to_string(path)?;
+            Ok(Some(
+                ron::de::from_str(&content).unwrap_or_else(|_| Data::new()),
+            ))
+        } else {
+            Ok(None)
+        }
+    }
+
+    /// Load everything saved for the chat, used when the bot starts
+    pub fn load_alias_data(&mut self, chat_id: u64) -> std::io::Result<&'static str> {
+        if let Some(data) = Self::read_alias_data(chat_id)? {
+            self.insert(chat_id, data);
         }
         Ok("Configuration loaded")
     }
 
+    /// Load back the saved aliases and settings, keeping the roll logs, sessions and the
+    /// rest of the running state as they are
+    pub fn reload_alias_data(&mut self, chat_id: u64) -> std::io::Result<&'static str> {
+        if let Some(saved) = Self::read_alias_data(chat_id)? {
+            self.restore_aliases(chat_id, saved);
+        }
+        Ok("Configuration loaded")
+    }
+
+    fn restore_aliases(&mut self, chat_id: u64, saved: Data) {
+        let data = self.entry(chat_id).or_insert_with(Data::new);
+        data.global_aliases = saved.global_aliases;
+        data.users_aliases = saved.users_aliases;
+        data.settings = saved.settings;
+    }
+
     pub fn save_all(&self) {
         let keys: Vec<_> = { self.keys().cloned().collect() };
         for chat_id in keys {
@@ -627,7 +678,7 @@ impl AllData {
                 }
                 path.push(format!("{}.ron", chat_id));
                 if let Err(e) = std::fs::write(path, ser.as_bytes()) {
-                    eprintln!("{}", e.to_string());
+                    eprintln!("{}", e);
                 }
             }
         }
@@ -698,6 +749,38 @@ mod tests {
         assert_eq!(expected, split_cmd("$fs + $attack + 4d4").unwrap());
     }
 
+    #[test]
+    fn split_aliases_separated_by_semicolon() {
+        let expected = vec![
+            SplitPart::Alias(Alias {
+                name: "att".to_string(),
+                args: Vec::new(),
+            }),
+            SplitPart::Expr(";".to_string()),
+            SplitPart::Alias(Alias {
+                name: "dmg".to_string(),
+                args: Vec::new(),
+            }),
+        ];
+        assert_eq!(expected, split_cmd("$att;$dmg").unwrap());
+    }
+
+    #[test]
+    fn expand_multiple_expressions_alias() {
+        let mut all = create_all_data();
+        all.set_user_alias(
+            "full_attack".to_string(),
+            "d20 + 5 ; $alias1 : longsword".to_string(),
+            0,
+            1,
+            "toto",
+        );
+        assert_eq!(
+            Ok(("d20 + 5 ; 1d10 : longsword".to_string(), true)),
+            all.expand_alias("$full_attack", 0, 1, true)
+        );
+    }
+
     #[test]
     fn get_global_alias_test() {
         let all = create_all_data();
@@ -917,6 +1000,27 @@ mod tests {
         );
     }
 
+    #[test]
+    fn restoring_aliases_keeps_running_state() {
+        let mut all = create_all_data();
+        all.new_clock(0, 5, "doom", 4).unwrap();
+        all.tick_clock(0, 5, "doom", 2).unwrap();
+        all.clear_aliases(0);
+
+        let mut saved = Data::new();
+        saved
+            .global_aliases
+            .insert("GALIAS1".to_string(), "1d4".to_string());
+        all.restore_aliases(0, saved);
+
+        assert_eq!(
+            Some(&"1d4".to_string()),
+            all[&0].global_aliases.get("GALIAS1")
+        );
+        assert!(all[&0].users_aliases.is_empty());
+        assert_eq!(2, all.clocks(0, 5).unwrap()["doom"].filled);
+    }
+
     fn create_all_data() -> AllData {
         let mut all = AllData::new();
         let mut data = Data::new();
@@ -0,0 +1,138 @@
+use std::{collections::BTreeMap, fmt::Display};
+
+use serde::{Deserialize, Serialize};
+
+use crate::alias::{AllData, Data};
+
+// Discord messages are limited, so are the clocks
+const MAX_SEGMENTS: u8 = 24;
+
+/// Progress clock of Forged in the Dark games: a named circle divided in segments
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+pub struct Clock {
+    pub segments: u8,
+    pub filled: u8,
+}
+
+impl Display for Clock {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let filled = "■".repeat(self.filled as usize);
+        let empty = "□".repeat((self.segments - self.filled) as usize);
+        write!(f, "[{}{}] {}/{}", filled, empty, self.filled, self.segments)?;
+        if self.filled == self.segments {
+            write!(f, " complete!")?;
+        }
+        Ok(())
+    }
+}
+
+impl AllData {
+    /// Clocks of the channel, by name
+    pub fn clocks(&self, chat_id: u64, channel_id: u64) -> Option<&BTreeMap<String, Clock>> {
+        self.get(&chat_id)
+            .and_then(|data| data.clocks.get(&channel_id))
+            .filter(|clocks| !clocks.is_empty())
+    }
+
+    pub fn new_clock(
+        &mut self,
+        chat_id: u64,
+        channel_id: u64,
+        name: &str,
+        segments: u8,
+    ) -> Result<String, String> {
+        if !(2..=MAX_SEGMENTS).contains(&segments) {
+            return Err(format!(
+                "A clock has between 2 and {} segments",
+                MAX_SEGMENTS
+            ));
+        }
+        let data = self.entry(chat_id).or_insert_with(Data::new);
+        let clocks = data.clocks.entry(channel_id).or_default();
+        if clocks.contains_key(name) {
+            return Err(format!("Clock `{}` already exists", name));
+        }
+        let clock = Clock {
+            segments,
+            filled: 0,
+        };
+        let msg = format!("{}: {}", name, clock);
+        clocks.insert(name.to_string(), clock);
+        Ok(msg)
+    }
+
+    /// Fill (or empty with a negative number) segments of the clock
+    pub fn tick_clock(
+        &mut self,
+        chat_id: u64,
+        channel_id: u64,
+        name: &str,
+        ticks: i16,
+    ) -> Result<String, String> {
+        let clock = self
+            .get_mut(&chat_id)
+            .and_then(|data| data.clocks.get_mut(&channel_id))
+            .and_then(|clocks| clocks.get_mut(name))
+            .ok_or_else(|| format!("No clock named `{}`", name))?;
+        // widened so a huge number of ticks can't overflow
+        let filled = clock.filled as i32 + ticks as i32;
+        clock.filled = filled.clamp(0, clock.segments as i32) as u8;
+        Ok(format!("{}: {}", name, clock))
+    }
+
+    pub fn delete_clock(
+        &mut self,
+        chat_id: u64,
+        channel_id: u64,
+        name: &str,
+    ) -> Result<String, String> {
+        self.get_mut(&chat_id)
+            .and_then(|data| data.clocks.get_mut(&channel_id))
+            .and_then(|clocks| clocks.remove(name))
+            .map(|_| format!("Clock `{}` deleted", name))
+            .ok_or_else(|| format!("No clock named `{}`", name))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn tick_clocks() {
+        let mut all = AllData::new();
+        assert!(all.clocks(0, 1).is_none());
+        assert_eq!(
+            Ok("Alarm: [□□□□□□] 0/6".to_string()),
+            all.new_clock(0, 1, "Alarm", 6)
+        );
+        assert!(all.new_clock(0, 1, "Alarm", 4).is_err());
+        assert!(all.new_clock(0, 1, "Huge", 100).is_err());
+
+        assert_eq!(
+            Ok("Alarm: [■■□□□□] 2/6".to_string()),
+            all.tick_clock(0, 1, "Alarm", 2)
+        );
+        assert_eq!(
+            Ok("Alarm: [■■■■■■] 6/6 complete!".to_string()),
+            all.tick_clock(0, 1, "Alarm", 10)
+        );
+        assert_eq!(
+            Ok("Alarm: [■■■■■□] 5/6".to_string()),
+            all.tick_clock(0, 1, "Alarm", -1)
+        );
+        assert_eq!(
+            Ok("Alarm: [■■■■■■] 6/6 complete!".to_string()),
+            all.tick_clock(0, 1, "Alarm", i16::MAX)
+        );
+        assert_eq!(
+            Ok("Alarm: [□□□□□□] 0/6".to_string()),
+            all.tick_clock(0, 1, "Alarm", i16::MIN)
+        );
+        assert!(all.tick_clock(0, 2, "Alarm", 1).is_err());
+
+        assert_eq!(1, all.clocks(0, 1).unwrap().len());
+        assert!(all.delete_clock(0, 1, "Alarm").is_ok());
+        assert!(all.clocks(0, 1).is_none());
+    }
+}
@@ -4,6 +4,7 @@ use futures::future::FutureExt;
 
 use serenity::{
     async_trait,
+    builder::CreateEmbed,
     client::{Context, EventHandler},
     framework::{
         standard::{
@@ -33,13 +34,27 @@ use crate::alias;
 mod cards_cmd;
 
 mod alias_cmd;
+mod clock_cmd;
+mod history_cmd;
+mod ironsworn_cmd;
+mod odds_cmd;
+mod resource_cmd;
 mod roll_cmd;
+mod session_cmd;
+mod stats_cmd;
 
 #[cfg(feature = "cards")]
 use cards_cmd::*;
 
 use alias_cmd::*;
+use clock_cmd::*;
+use history_cmd::*;
+use ironsworn_cmd::*;
+use odds_cmd::*;
+use resource_cmd::*;
 use roll_cmd::*;
+use session_cmd::*;
+use stats_cmd::*;
 
 pub(crate) struct FrameworkContainer;
 impl TypeMapKey for FrameworkContainer {
@@ -131,7 +146,7 @@ impl EventHandler for Handler {
 
 async fn strikethrough_previous_reply(ctx: Context, ref_msg_id: MessageId, channel_id: ChannelId) {
     match channel_id
-        .messages(&ctx.http, |retriever| retriever.after(&ref_msg_id))
+        .messages(&ctx.http, |retriever| retriever.after(ref_msg_id))
         .await
     {
         // messages.len() == 1 means that the edited message didn't trigger a roll, no need to
@@ -143,16 +158,33 @@ async fn strikethrough_previous_reply(ctx: Context, ref_msg_id: MessageId, chann
                     Some(ref_msg) => {
                         ref_msg.id == ref_msg_id && 
                         // Do not strikethrough a message twice
-                        !m.content.starts_with("~~") 
+                        !m.content.starts_with("~~") &&
+                        !m.embeds.iter().any(|e| matches!(&e.title, Some(t) if t.starts_with("~~")))
                     }
                     None => false,
                 }
             }) {
-                let content = msg_to_edit.content.clone();
-                if let Err(e) = msg_to_edit
-                    .edit(&ctx, |new_msg| new_msg.content(&format!("~~{}~~", content)))
-                    .await
-                {
+                let res = match msg_to_edit.embeds.first().cloned() {
+                    // a roll displayed as an embed has no content, strikethrough its title
+                    Some(embed) if msg_to_edit.content.is_empty() => {
+                        let title = format!("~~{}~~", embed.title.clone().unwrap_or_default());
+                        msg_to_edit
+                            .edit(&ctx, |new_msg| {
+                                new_msg.embed(|e| {
+                                    *e = CreateEmbed::from(embed);
+                                    e.title(title)
+                                })
+                            })
+                            .await
+                    }
+                    _ => {
+                        let content = msg_to_edit.content.clone();
+                        msg_to_edit
+                            .edit(&ctx, |new_msg| new_msg.content(format!("~~{}~~", content)))
+                            .await
+                    }
+                };
+                if let Err(e) = res {
                     eprintln!("Error while editing: {}", e);
                 }
             }
@@ -190,6 +222,10 @@ async fn my_help(
     groups: &[&'static CommandGroup],
     owners: HashSet<UserId>,
 ) -> CommandResult {
+    if args.rest().trim() == "systems" {
+        send_message(context, msg, &crate::systems::help()).await?;
+        return Ok(());
+    }
     let _ = help_commands::with_embeds(context, msg, args, help_options, groups, owners).await;
     Ok(())
 }
@@ -216,6 +252,32 @@ pub(crate) async fn send_message(
     }
 }
 
+#[inline]
+pub(crate) async fn send_embed<F>(
+    ctx: &Context,
+    orig_msg: &Message,
+    f: F,
+) -> Result<Message, serenity::Error>
+where
+    F: FnOnce(&mut CreateEmbed) -> &mut CreateEmbed,
+{
+    match orig_msg
+        .channel_id
+        .send_message(&ctx.http, |m| {
+            m.reference_message(orig_msg)
+                .allowed_mentions(|am| am.replied_user(true))
+                .embed(f)
+        })
+        .await
+    {
+        Err(e) => {
+            eprintln!("Error sending message: {:?}", e);
+            Err(e)
+        }
+        Ok(msg) => Ok(msg),
+    }
+}
+
 pub async fn run() {
     // Configure the client with your Discord bot token in the environment.
     let token = env::var("DISCORD_TOKEN");
@@ -225,6 +287,18 @@ pub async fn run() {
     }
 
     let token = token.unwrap();
+
+    // reproducible rolls, to replay a bug report
+    if let Ok(seed) = env::var("DISLE_SEED") {
+        match seed.parse::<u64>() {
+            Ok(seed) => {
+                crate::rng::set_global_seed(seed);
+                println!("Rolls are reproducible with seed {}", seed);
+            }
+            Err(e) => eprintln!("Ignoring `DISLE_SEED`: {}", e),
+        }
+    }
+
     let http = Http::new_with_token(&token);
 
     // We will fetch your bot's owners and id
@@ -249,7 +323,14 @@ pub async fn run() {
         .on_dispatch_error(dispatch_error)
         .help(&MY_HELP)
         .group(&ROLL_GROUP)
-        .group(&ALIAS_GROUP);
+        .group(&ALIAS_GROUP)
+        .group(&ODDS_GROUP)
+        .group(&HISTORY_GROUP)
+        .group(&SESSION_GROUP)
+        .group(&STATS_GROUP)
+        .group(&RESOURCE_GROUP)
+        .group(&CLOCK_GROUP)
+        .group(&IRONSWORN_GROUP);
 
     #[cfg(feature = "cards")]
     let std_framework = std_framework.group(&CARDS_GROUP);
@@ -269,6 +350,9 @@ pub async fn run() {
         let mut data = client.data.write().await;
         data.insert::<InitDMTable>(HashSet::new());
         data.insert::<RerollTable>(HashMap::new());
+        data.insert::<YearZeroRolls>(HashMap::new());
+        data.insert::<SecretRolls>(HashMap::new());
+        data.insert::<BlindRolls>(HashMap::new());
         data.insert::<Aliases>(alias::AllData::new());
         data.insert::<FrameworkContainer>(framework);
         data.insert::<AliasMgrRole>(HashMap::new());
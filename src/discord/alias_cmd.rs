@@ -48,7 +48,7 @@ impl TypeMapKey for InitDMTable {
 )]
 struct Alias;
 
-async fn is_allowed(ctx: &Context, msg: &Message) -> bool {
+pub(crate) async fn is_allowed(ctx: &Context, msg: &Message) -> bool {
     let data = ctx.data.read().await;
     let all_roles = data.get::<AliasMgrRole>().unwrap();
     match msg.guild_id {
@@ -79,11 +79,11 @@ pub(crate) fn chat_id(msg: &Message) -> u64 {
 pub(crate) async fn parse_alias(
     ctx: &Context,
     msg: &Message,
-    args: Args,
+    input: &str,
 ) -> Result<(String, bool), String> {
     let data = ctx.data.read().await;
     let all_data = data.get::<Aliases>().unwrap();
-    all_data.expand_alias(args.rest(), chat_id(msg), *msg.author.id.as_u64(), true)
+    all_data.expand_alias(input, chat_id(msg), *msg.author.id.as_u64(), true)
 }
 
 pub(crate) async fn load_private_alias(ctx: Context, channel_id: u64) {
@@ -109,7 +109,7 @@ pub(crate) async fn load_private_alias(ctx: Context, channel_id: u64) {
     }
 }
 
-async fn get_user_name(ctx: &Context, msg: &Message) -> String {
+pub(crate) async fn get_user_name(ctx: &Context, msg: &Message) -> String {
     match msg.guild_id {
         Some(guild_id) => msg
             .author
@@ -297,14 +297,14 @@ async fn save_alias(ctx: &Context, msg: &Message, _args: Args) -> CommandResult
 /// ```
 /// /alias load
 ///
-/// Load persistent alias data
+/// Load back the saved aliases and settings
 /// ```
 async fn load_alias(ctx: &Context, msg: &Message, _args: Args) -> CommandResult {
     let msg_to_send = if is_allowed(ctx, msg).await {
         let mut data = ctx.data.write().await;
         let all_data = data.get_mut::<Aliases>().unwrap();
 
-        all_data.load_alias_data(chat_id(msg))?
+        all_data.reload_alias_data(chat_id(msg))?
     } else {
         "Only allowed users can load the configuration"
     };
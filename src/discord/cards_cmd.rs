@@ -3,7 +3,8 @@ use std::{
     ops::{Deref, DerefMut},
 };
 
-use caith::cards::{Card, Deck};
+use caith::cards::{Card, Deck, Suit};
+use rand::seq::SliceRandom;
 use serenity::{
     client::Context,
     framework::standard::{
@@ -14,7 +15,12 @@ use serenity::{
     prelude::TypeMapKey,
 };
 
-use super::alias_cmd::chat_id;
+use crate::{
+    rng::{default_rng, DiceRng},
+    session::EventKind,
+};
+
+use super::{alias_cmd::chat_id, session_cmd::record_event};
 
 pub(crate) struct Decks;
 impl TypeMapKey for Decks {
@@ -78,7 +84,7 @@ impl AllPrivateDraws {
 }
 
 #[group]
-#[commands(draw, newdeck, shuffle, remain, reveal, discard)]
+#[commands(draw, newdeck, shuffle, remain, discard)]
 struct Cards;
 
 #[command]
@@ -106,7 +112,7 @@ async fn shuffle(ctx: &Context, msg: &Message, _args: Args) -> CommandResult {
     let mut data = ctx.data.write().await;
     let decks = data.get_mut::<Decks>().unwrap();
     match decks.get_mut(&chat_id(msg).to_string()) {
-        Some(deck) if !deck.is_empty() => deck.shuffle(),
+        Some(deck) if !deck.is_empty() => shuffle_deck(deck, &mut default_rng()),
         Some(_) => {
             super::send_message(ctx, msg, "Deck is empty").await?;
         }
@@ -141,13 +147,13 @@ async fn newdeck(ctx: &Context, msg: &Message, mut args: Args) -> CommandResult
         let decks = data.get_mut::<Decks>().unwrap();
         match decks.get_mut(&chat_id(msg).to_string()) {
             Some(deck) => {
-                deck.reset(number as usize);
+                *deck = new_deck(number as usize, &mut default_rng());
                 true
             }
             None => {
                 decks.insert(
                     chat_id(msg).to_string(),
-                    caith::cards::Deck::new(number as usize),
+                    new_deck(number as usize, &mut default_rng()),
                 );
                 false
             }
@@ -173,16 +179,20 @@ async fn newdeck(ctx: &Context, msg: &Message, mut args: Args) -> CommandResult
     Ok(())
 }
 
-#[command]
-#[aliases("rev")]
-/// Reveal your secret draw
-///
-async fn reveal(ctx: &Context, msg: &Message, _args: Args) -> CommandResult {
+/// Reveal the secret draw of the user, called by `/reveal`
+pub(crate) async fn reveal_draw(ctx: &Context, msg: &Message) -> CommandResult {
+    let mut revealed = None;
     do_on_private_draw(ctx, msg, |privates, id| match privates.get(&id) {
-        Some(privates) if !privates.is_empty() => Ok(Some(print_vec_of_cards(privates))),
+        Some(privates) if !privates.is_empty() => {
+            revealed = Some(print_vec_of_cards(privates));
+            Ok(revealed.clone())
+        }
         Some(_) | None => Err("You don't have any private draw"),
     })
     .await?;
+    if let Some(cards) = revealed {
+        record_event(ctx, msg, EventKind::Reveal, "", &cards).await;
+    }
     Ok(())
 }
 
@@ -215,6 +225,7 @@ async fn draw(ctx: &Context, msg: &Message, args: Args) -> CommandResult {
     let (nb, reason, secret) = process_draw_args(ctx, msg, &args).await?;
 
     let mut drawn_cards = draw_card(ctx, msg, nb).await?;
+    let drawn = !drawn_cards.is_empty() && drawn_cards.len() == nb as usize;
 
     let mut msg_to_send = if drawn_cards.is_empty() {
         "Deck was empty".to_string()
@@ -238,6 +249,14 @@ async fn draw(ctx: &Context, msg: &Message, args: Args) -> CommandResult {
     if let Some(reason) = reason {
         msg_to_send.push_str(&format!(": `{}`", reason));
     }
+    if drawn {
+        let result = if secret {
+            "secret draw"
+        } else {
+            msg_to_send.as_str()
+        };
+        record_event(ctx, msg, EventKind::Draw, args.message(), result).await;
+    }
     if !secret {
         super::send_message(ctx, msg, &msg_to_send).await?;
     } else {
@@ -255,6 +274,28 @@ async fn draw(ctx: &Context, msg: &Message, args: Args) -> CommandResult {
     Ok(())
 }
 
+// `Deck` shuffles with its own RNG, put the cards back in order before shuffling with ours so
+// the deck only depends on `rng`
+fn new_deck(nb_of_jokers: usize, rng: &mut DiceRng) -> Deck {
+    let mut deck = Deck::new(nb_of_jokers);
+    deck.sort_by_key(|card| {
+        let suit = match card.suit {
+            Suit::Clubs => 0,
+            Suit::Diamonds => 1,
+            Suit::Hearts => 2,
+            Suit::Spades => 3,
+            Suit::None => 4,
+        };
+        (suit, card.value)
+    });
+    shuffle_deck(&mut deck, rng);
+    deck
+}
+
+fn shuffle_deck(deck: &mut Deck, rng: &mut DiceRng) {
+    deck.as_mut_slice().shuffle(rng);
+}
+
 fn print_vec_of_cards(v: &[Card]) -> String {
     v.iter().enumerate().fold(String::new(), |mut s, (i, c)| {
         if i != 0 {
@@ -269,7 +310,7 @@ fn print_vec_of_cards(v: &[Card]) -> String {
         };
 
         match c.suit {
-            caith::cards::Suit::None => s.push_str(&suit.to_string()),
+            caith::cards::Suit::None => s.push_str(suit),
             _ => s.push_str(&format!("{}{}", c.value, suit)),
         }
 
@@ -320,7 +361,7 @@ async fn process_draw_args<'a>(
         },
         None => false,
     };
-    let end = secret_idx.unwrap_or_else(|| args.len());
+    let end = secret_idx.unwrap_or(args.len());
     let (nb, reason) = match reason_idx {
         Some(idx) => {
             let end = if end < idx { end } else { idx };
@@ -373,3 +414,33 @@ async fn parse_number(ctx: &Context, msg: &Message, param: &str) -> Result<u32,
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::rng::rng_from_seed;
+
+    #[test]
+    fn draw_is_reproducible() {
+        let mut deck = new_deck(2, &mut rng_from_seed(1));
+        assert_eq!(54, deck.len());
+        assert_eq!(
+            "4:diamonds:, 1:clubs:, 1:spades:, 5:hearts:, 7:hearts:",
+            print_vec_of_cards(&deck.draw(5))
+        );
+
+        assert_eq!(
+            "10:spades:, 11:spades:, 7:diamonds:",
+            print_vec_of_cards(&deck.draw(3))
+        );
+
+        // the remaining cards are shuffled, the next draws follow the new order
+        shuffle_deck(&mut deck, &mut rng_from_seed(2));
+        assert_eq!(
+            "11:hearts:, 3:spades:, 10:diamonds:, 2:clubs:",
+            print_vec_of_cards(&deck.draw(4))
+        );
+        assert_eq!("9:diamonds:, 1:hearts:", print_vec_of_cards(&deck.draw(2)));
+        assert_eq!(40, deck.len());
+    }
+}
@@ -0,0 +1,74 @@
+use serenity::{
+    client::Context,
+    framework::standard::{
+        macros::{command, group},
+        Args, CommandResult,
+    },
+    model::channel::Message,
+};
+
+use super::{
+    alias_cmd::{chat_id, Aliases},
+    send_message,
+};
+
+#[group]
+#[commands(clock)]
+struct Clock;
+
+const CLOCK_USAGE: &str =
+    "Bad parameter: `/clock [new <name> <segments> | tick <name> [n] | del <name>]`";
+
+#[command]
+/// ```
+/// /clock [new <name> <segments> | tick <name> [n] | del <name>]
+///
+/// Progress clocks of the channel, as in Forged in the Dark games. Without parameter, show the
+/// clocks. `tick` fills one segment, or `n` (a negative number empties segments).
+/// Use quotes for names with spaces.
+///
+/// Ex: `/clock new "Alarm" 6`, `/clock tick Alarm 2`
+/// ```
+async fn clock(ctx: &Context, msg: &Message, mut args: Args) -> CommandResult {
+    let cmd = args.single::<String>().unwrap_or_default().to_lowercase();
+    let name = args.single_quoted::<String>().unwrap_or_default();
+    let channel_id = *msg.channel_id.as_u64();
+    let mut data = ctx.data.write().await;
+    let all_data = data.get_mut::<Aliases>().unwrap();
+    let msg_to_send = match cmd.as_str() {
+        "" => match all_data.clocks(chat_id(msg), channel_id) {
+            Some(clocks) => clocks
+                .iter()
+                .map(|(name, clock)| format!("{}: {}\n", name, clock))
+                .collect(),
+            None => "No clock in this channel".to_string(),
+        },
+        _ if name.is_empty() => CLOCK_USAGE.to_string(),
+        "new" => match args.single::<u8>() {
+            Ok(segments) => all_data
+                .new_clock(chat_id(msg), channel_id, &name, segments)
+                .unwrap_or_else(|err| err),
+            Err(_) => CLOCK_USAGE.to_string(),
+        },
+        "tick" => {
+            let ticks = if args.is_empty() {
+                Ok(1)
+            } else {
+                args.single::<i16>()
+            };
+            match ticks {
+                Ok(ticks) => all_data
+                    .tick_clock(chat_id(msg), channel_id, &name, ticks)
+                    .unwrap_or_else(|err| err),
+                Err(_) => CLOCK_USAGE.to_string(),
+            }
+        }
+        "del" | "delete" => all_data
+            .delete_clock(chat_id(msg), channel_id, &name)
+            .unwrap_or_else(|err| err),
+        _ => CLOCK_USAGE.to_string(),
+    };
+    drop(data);
+    send_message(ctx, msg, &msg_to_send).await?;
+    Ok(())
+}
@@ -0,0 +1,107 @@
+use serenity::{
+    client::Context,
+    framework::standard::{
+        macros::{command, group},
+        Args, CommandResult,
+    },
+    model::{channel::Message, id::UserId},
+};
+
+use crate::history::LogEntry;
+
+use super::{
+    alias_cmd::{chat_id, Aliases},
+    send_message,
+};
+
+#[group]
+#[commands(history)]
+struct History;
+
+const DEFAULT_HISTORY_LEN: usize = 5;
+const MAX_HISTORY_LEN: usize = 20;
+// Discord's limit
+const MAX_MESSAGE_LEN: usize = 2000;
+
+fn format_entry(entry: &LogEntry) -> String {
+    let crit = match (entry.crit_max, entry.crit_min) {
+        (true, true) => " 🥳🤬",
+        (true, false) => " 🥳",
+        (false, true) => " 🤬",
+        (false, false) => "",
+    };
+    let expansion = match &entry.expansion {
+        Some(expansion) => format!(" (`{}`)", expansion),
+        None => String::new(),
+    };
+    format!(
+        "<t:{}:t> **{}**: `{}`{} → {}{}",
+        entry.timestamp,
+        entry.user_name,
+        entry.expression,
+        expansion,
+        entry.result.trim_end().replace('\n', " "),
+        crit
+    )
+}
+
+#[command]
+#[aliases("hist")]
+/// ```
+/// /history [user] [number]
+///
+/// Show the last rolls of the channel, or of the mentioned user.
+/// Default to the 5 last rolls, 20 at most.
+///
+/// Ex: `/history @Geob 10`
+/// ```
+async fn history(ctx: &Context, msg: &Message, mut args: Args) -> CommandResult {
+    let mut user_id = None;
+    let mut nb = DEFAULT_HISTORY_LEN;
+    while !args.is_empty() {
+        let arg = args.single::<String>().unwrap();
+        if arg.starts_with("<@") {
+            match arg.parse::<UserId>() {
+                Ok(id) => user_id = Some(*id.as_u64()),
+                Err(_) => {
+                    send_message(ctx, msg, "Bad parameter: expected a user mention").await?;
+                    return Ok(());
+                }
+            }
+        } else {
+            match arg.parse::<usize>() {
+                Ok(n) => nb = n.clamp(1, MAX_HISTORY_LEN),
+                Err(_) => {
+                    send_message(ctx, msg, "Bad parameter: `/history [user] [number]`").await?;
+                    return Ok(());
+                }
+            }
+        }
+    }
+
+    let msg_to_send = {
+        let data = ctx.data.read().await;
+        let all_data = data.get::<Aliases>().unwrap();
+        let entries = all_data.roll_history(chat_id(msg), *msg.channel_id.as_u64(), user_id, nb);
+        if entries.is_empty() {
+            "No roll in history".to_string()
+        } else {
+            // keep the most recent rolls if they don't fit in one message
+            let mut lines = Vec::new();
+            let mut len = 0;
+            for entry in entries.iter().rev() {
+                let line = format_entry(entry);
+                len += line.len() + 1;
+                if len > MAX_MESSAGE_LEN {
+                    break;
+                }
+                lines.push(line);
+            }
+            lines.reverse();
+            lines.join("\n")
+        }
+    };
+
+    send_message(ctx, msg, &msg_to_send).await?;
+    Ok(())
+}
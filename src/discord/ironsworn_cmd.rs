@@ -0,0 +1,105 @@
+use serenity::{
+    client::Context,
+    framework::standard::{
+        macros::{command, group},
+        Args, CommandResult,
+    },
+    model::channel::Message,
+};
+
+use super::{
+    alias_cmd::{chat_id, Aliases},
+    roll_cmd::roll_call,
+    send_message,
+};
+use crate::systems::oracle;
+
+#[group]
+#[commands(progress, oracle)]
+struct Ironsworn;
+
+const PROGRESS_USAGE: &str = "Bad parameter: `/progress [new <name> <rank> | mark <name> [n] | \
+                              roll <name> | del <name>]`";
+
+#[command]
+#[aliases("pg")]
+/// ```
+/// /progress [new <name> <rank> | mark <name> [n] | roll <name> | del <name>]
+///
+/// Ironsworn progress tracks of your character. Without parameter, show the tracks.
+/// The rank is troublesome, dangerous, formidable, extreme or epic. `mark` marks progress once,
+/// or `n` times (a negative number clears progress). `roll` makes a progress roll.
+/// Use quotes for names with spaces.
+///
+/// Ex: `/progress new "Find my brother" dangerous`, `/progress mark "Find my brother"`
+/// ```
+async fn progress(ctx: &Context, msg: &Message, mut args: Args) -> CommandResult {
+    let cmd = args.single::<String>().unwrap_or_default().to_lowercase();
+    let name = args.single_quoted::<String>().unwrap_or_default();
+    let user_id = *msg.author.id.as_u64();
+    let mut data = ctx.data.write().await;
+    let all_data = data.get_mut::<Aliases>().unwrap();
+    let msg_to_send = match cmd.as_str() {
+        "" => match all_data.progress_tracks(chat_id(msg), user_id) {
+            Some(tracks) => tracks
+                .iter()
+                .map(|(name, track)| format!("{}: {}\n", name, track))
+                .collect(),
+            None => "No progress track".to_string(),
+        },
+        _ if name.is_empty() => PROGRESS_USAGE.to_string(),
+        "new" => match args.single::<String>() {
+            Ok(rank) => rank
+                .parse()
+                .and_then(|rank| all_data.new_track(chat_id(msg), user_id, &name, rank))
+                .unwrap_or_else(|err| err),
+            Err(_) => PROGRESS_USAGE.to_string(),
+        },
+        "mark" => {
+            let marks = if args.is_empty() {
+                Ok(1)
+            } else {
+                args.single::<i16>()
+            };
+            match marks {
+                Ok(marks) => all_data
+                    .mark_progress(chat_id(msg), user_id, &name, marks)
+                    .unwrap_or_else(|err| err),
+                Err(_) => PROGRESS_USAGE.to_string(),
+            }
+        }
+        "roll" => match all_data.progress_score(chat_id(msg), user_id, &name) {
+            Ok(score) => {
+                drop(data);
+                return roll_call(ctx, msg, &format!("ironp({}) : {}", score, name)).await;
+            }
+            Err(err) => err,
+        },
+        "del" | "delete" => all_data
+            .delete_track(chat_id(msg), user_id, &name)
+            .unwrap_or_else(|err| err),
+        _ => PROGRESS_USAGE.to_string(),
+    };
+    drop(data);
+    send_message(ctx, msg, &msg_to_send).await?;
+    Ok(())
+}
+
+#[command]
+/// ```
+/// /oracle [table or odds]
+///
+/// Ask an Ironsworn oracle: a prompt from a table, or the answer to a yes/no question with its
+/// odds. Without parameter, list the tables and odds.
+///
+/// Ex: `/oracle action`, `/oracle pay the price`, `/oracle likely`
+/// ```
+async fn oracle(ctx: &Context, msg: &Message, args: Args) -> CommandResult {
+    let question = args.rest().trim();
+    if question.is_empty() {
+        send_message(ctx, msg, &oracle::list()).await?;
+        Ok(())
+    } else {
+        roll_call(ctx, msg, &format!("oracle({})", question)).await
+    }
+}
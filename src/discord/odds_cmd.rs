@@ -0,0 +1,205 @@
+use serenity::{
+    client::Context,
+    framework::standard::{
+        macros::{command, group},
+        Args, CommandResult,
+    },
+    model::channel::Message,
+};
+
+use crate::{
+    odds::Distribution,
+    rng::{default_rng, DiceRng},
+};
+
+use super::{alias_cmd::parse_alias, send_message};
+
+#[group]
+#[commands(odds, compare)]
+struct Odds;
+
+enum Threshold {
+    AtLeast(i64),
+    AtMost(i64),
+}
+
+// Split `<expr> >= <value>` into the expression and the threshold
+fn split_threshold(input: &str) -> Result<(&str, Option<Threshold>), String> {
+    let (idx, at_least) = match (input.rfind(">="), input.rfind("<=")) {
+        (Some(idx), _) => (idx, true),
+        (None, Some(idx)) => (idx, false),
+        (None, None) => return Ok((input, None)),
+    };
+    let value = input[idx + 2..]
+        .trim()
+        .parse::<i64>()
+        .map_err(|_| "Syntax error, expected: `<expression> >= <number>`".to_string())?;
+    let threshold = if at_least {
+        Threshold::AtLeast(value)
+    } else {
+        Threshold::AtMost(value)
+    };
+    Ok((input[..idx].trim(), Some(threshold)))
+}
+
+// Computing the distribution can roll the expression thousands of times
+pub(crate) async fn compute_distribution(
+    expr: String,
+    mut rng: DiceRng,
+) -> Result<Distribution, String> {
+    tokio::task::spawn_blocking(move || Distribution::compute(&expr, &mut rng))
+        .await
+        .map_err(|e| e.to_string())?
+}
+
+pub(crate) fn method_name(dist: &Distribution) -> String {
+    match dist.samples() {
+        Some(nb) => format!("{} rolls", nb),
+        None => "exact".to_string(),
+    }
+}
+
+fn format_odds(expr: &str, dist: &Distribution, threshold: Option<Threshold>) -> String {
+    let mut s = format!(
+        "Odds of `{}` ({}):\nMean: **{:.2}**, standard deviation: {:.2}\n",
+        expr,
+        method_name(dist),
+        dist.mean(),
+        dist.std_dev()
+    );
+    let percentiles: Vec<String> = [10, 25, 50, 75, 90]
+        .iter()
+        .map(|p| format!("{}%: {}", p, dist.percentile(*p as f64 / 100.0)))
+        .collect();
+    s.push_str(&format!("Percentiles: {}\n", percentiles.join(", ")));
+    match threshold {
+        Some(Threshold::AtLeast(value)) => s.push_str(&format!(
+            "Chance to get {} or more: **{:.2}%**\n",
+            value,
+            dist.prob_at_least(value) * 100.0
+        )),
+        Some(Threshold::AtMost(value)) => s.push_str(&format!(
+            "Chance to get {} or less: **{:.2}%**\n",
+            value,
+            dist.prob_at_most(value) * 100.0
+        )),
+        None => (),
+    }
+    s.push_str(&format!("```\n{}```", dist.histogram()));
+    s
+}
+
+#[command]
+/// ```
+/// /odds <expression> [>= value | <= value]
+///
+/// Compute the distribution of the expression's total: mean, standard deviation,
+/// percentiles and a histogram. With `>=` or `<=`, give the chance to reach the value.
+///
+/// Sums of dices and constants are computed exactly, other expressions are rolled
+/// many times.
+///
+/// Ex: `/odds 6d10 t8 >= 3` gives the chance to get 3 successes or more.
+/// ```
+async fn odds(ctx: &Context, msg: &Message, args: Args) -> CommandResult {
+    let input = args.rest();
+    let msg_to_send = if input.is_empty() || input.starts_with("help") {
+        "To get help, run `/help odds`".to_string()
+    } else {
+        match split_threshold(input) {
+            Ok((expr, threshold)) => match parse_alias(ctx, msg, expr).await {
+                Ok((expr, has_alias)) => {
+                    match compute_distribution(expr.clone(), default_rng()).await {
+                        Ok(dist) => {
+                            let odds = format_odds(&expr, &dist, threshold);
+                            if has_alias {
+                                format!("Alias expansion: `{}`\n{}", expr, odds)
+                            } else {
+                                odds
+                            }
+                        }
+                        Err(err) => err,
+                    }
+                }
+                Err(err) => err,
+            },
+            Err(err) => err,
+        }
+    };
+    send_message(ctx, msg, &msg_to_send).await?;
+    Ok(())
+}
+
+// Split `<expr A> vs <expr B>`
+fn split_versus(input: &str) -> Result<(&str, &str), String> {
+    let idx = input
+        .to_ascii_lowercase()
+        .find(" vs ")
+        .ok_or_else(|| "Syntax error, expected: `<expression> vs <expression>`".to_string())?;
+    Ok((input[..idx].trim(), input[idx + 4..].trim()))
+}
+
+async fn expand_and_compute(
+    ctx: &Context,
+    msg: &Message,
+    expr: &str,
+) -> Result<(String, bool, Distribution), String> {
+    let (expr, has_alias) = parse_alias(ctx, msg, expr).await?;
+    let dist = compute_distribution(expr.clone(), default_rng()).await?;
+    Ok((expr, has_alias, dist))
+}
+
+#[command]
+#[aliases("cmp")]
+/// ```
+/// /compare <expression A> vs <expression B>
+///
+/// Give the probability that A beats, ties or loses to B, and the distribution of the
+/// difference between A and B.
+///
+/// Ex: `/compare 2d6 + 3 vs 1d12 + 4`
+/// ```
+async fn compare(ctx: &Context, msg: &Message, args: Args) -> CommandResult {
+    let input = args.rest();
+    let msg_to_send = if input.is_empty() || input.starts_with("help") {
+        "To get help, run `/help compare`".to_string()
+    } else {
+        match split_versus(input) {
+            Ok((expr_a, expr_b)) => {
+                let a = expand_and_compute(ctx, msg, expr_a).await;
+                let b = expand_and_compute(ctx, msg, expr_b).await;
+                match (a, b) {
+                    (Ok((expr_a, alias_a, dist_a)), Ok((expr_b, alias_b, dist_b))) => {
+                        let diff = dist_a.difference(&dist_b, &mut default_rng());
+                        let alias_expansion = if alias_a || alias_b {
+                            format!("Alias expansion: `{}` vs `{}`\n", expr_a, expr_b)
+                        } else {
+                            String::new()
+                        };
+                        format!(
+                            "{}`{}` ({}) vs `{}` ({}):\n\
+                            A wins: **{:.2}%**, tie: **{:.2}%**, B wins: **{:.2}%**\n\
+                            Difference A - B: mean **{:.2}**, standard deviation {:.2}\n\
+                            ```\n{}```",
+                            alias_expansion,
+                            expr_a,
+                            method_name(&dist_a),
+                            expr_b,
+                            method_name(&dist_b),
+                            diff.prob_at_least(1) * 100.0,
+                            diff.prob_of(0) * 100.0,
+                            diff.prob_at_most(-1) * 100.0,
+                            diff.mean(),
+                            diff.std_dev(),
+                            diff.histogram()
+                        )
+                    }
+                    (Err(err), _) | (_, Err(err)) => err,
+                }
+            }
+            Err(err) => err,
+        }
+    };
+    send_message(ctx, msg, &msg_to_send).await?;
+    Ok(())
+}
@@ -0,0 +1,68 @@
+use serenity::{
+    client::Context,
+    framework::standard::{
+        macros::{command, group},
+        Args, CommandResult,
+    },
+    model::channel::Message,
+};
+
+use crate::resources::ResourceChange;
+
+use super::{
+    alias_cmd::{chat_id, Aliases},
+    send_message,
+};
+
+#[group]
+#[commands(resource)]
+struct Resource;
+
+#[command]
+#[aliases("res")]
+/// ```
+/// /resource (or /res) [name [amount | +n | -n]]
+///
+/// Track the resources of your character, like bennies or willpower points. Without parameter,
+/// list your resources. With a name, show it, set it to the amount, or add or remove points.
+/// `/push <name>` spends one point to push a roll.
+///
+/// Ex: `/res benny 3`, `/res willpower -1`
+/// ```
+async fn resource(ctx: &Context, msg: &Message, mut args: Args) -> CommandResult {
+    let name = args.single::<String>().unwrap_or_default().to_lowercase();
+    let change = args.rest().trim();
+    let user_id = *msg.author.id.as_u64();
+    let msg_to_send = if change.is_empty() {
+        let data = ctx.data.read().await;
+        let all_data = data.get::<Aliases>().unwrap();
+        match all_data.resources(chat_id(msg), user_id) {
+            Some(resources) if name.is_empty() => {
+                let list: Vec<String> = resources
+                    .iter()
+                    .map(|(name, amount)| format!("{}: **{}**", name, amount))
+                    .collect();
+                format!("Your resources: {}", list.join(", "))
+            }
+            Some(resources) => format!(
+                "{}: **{}**",
+                name,
+                resources.get(&name).copied().unwrap_or(0)
+            ),
+            None if name.is_empty() => "You don't have any resource".to_string(),
+            None => format!("{}: **0**", name),
+        }
+    } else {
+        match change.parse::<ResourceChange>() {
+            Ok(change) => {
+                let mut data = ctx.data.write().await;
+                let all_data = data.get_mut::<Aliases>().unwrap();
+                let amount = all_data.change_resource(chat_id(msg), user_id, &name, change);
+                format!("{}: **{}**", name, amount)
+            }
+            Err(err) => err,
+        }
+    };
+    send_message(ctx, msg, &msg_to_send).await?;
+    Ok(())
+}
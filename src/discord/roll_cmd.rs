@@ -1,63 +1,247 @@
 use std::{
-    borrow::Cow,
-    collections::{HashMap, HashSet},
+    collections::{HashMap, HashSet, VecDeque},
+    fmt::{Display, Write},
+    str::FromStr,
 };
 
-use caith::{Critic, RollResult};
+use caith::{Critic, DiceResult, RollHistory, RollResult, RollResultType};
 
 use serenity::{
+    builder::CreateEmbed,
     client::Context,
     framework::standard::{
         macros::{command, group},
-        Args, CommandResult,
+        Args, CommandError, CommandResult, Delimiter,
+    },
+    model::channel::{Channel, Message, ReactionType},
+    model::{
+        guild::Member,
+        id::{GuildId, RoleId},
     },
-    model::channel::Message,
-    model::channel::ReactionType,
     prelude::TypeMapKey,
+    utils::Colour,
 };
 
-use crate::Interpreter;
+use crate::{
+    fair::{check_commitment, commit, FairRoll},
+    history::LogEntry,
+    rng::{default_rng, DiceRng},
+    settings::OutputFormat,
+    stats::{dice_sides, dice_terms, DiceTerm, UserStats},
+    systems::{yze::YzeRoll, Outcome},
+};
 
-use super::{alias_cmd::parse_alias, err_message, send_message};
+use super::{
+    alias_cmd::{chat_id, get_user_name, is_allowed, parse_alias, Aliases},
+    err_message, send_embed, send_message,
+};
 
 #[group]
-#[commands(roll, reroll, reroll_dice, disle)]
+#[commands(
+    roll,
+    gmroll,
+    blindroll,
+    reveal,
+    reroll,
+    reroll_dice,
+    push,
+    format,
+    gmrole,
+    disle
+)]
 struct Roll;
 
+// Last roll of a user, for `/reroll` and `/reroll_dice`
+pub(crate) struct LastRoll {
+    roller: caith::Roller,
+    // history of the result, updated by `/reroll_dice` and `/push`, `None` for repeated rolls
+    history: Option<Vec<RollHistory>>,
+    // the displayed result
+    result: String,
+    // the game system call, rerolled with its interpretation, ex: `coc(60)`
+    system: Option<String>,
+}
+
+impl LastRoll {
+    fn new(roller: &caith::Roller, res: &RollResult, system: Option<&str>) -> Self {
+        // do not store comment for reroll
+        let mut roller = roller.clone();
+        roller.trim_reason();
+        LastRoll {
+            roller,
+            history: res.as_single().map(|res| res.get_history().clone()),
+            result: res.to_string(),
+            system: system.map(str::to_string),
+        }
+    }
+
+    // What `/reroll` rolls again, without the space left by the removed reason
+    fn expression(&self) -> &str {
+        self.system
+            .as_deref()
+            .unwrap_or_else(|| self.roller.as_str())
+            .trim()
+    }
+}
+
 pub(crate) struct RerollTable;
 impl TypeMapKey for RerollTable {
-    type Value = HashMap<String, caith::Roller>;
+    // "user#channel", last rolls of distinct expressions, most recent first
+    type Value = HashMap<String, VecDeque<LastRoll>>;
+}
+
+// Number of expressions `/reroll` can go back to
+const MAX_REROLL_HISTORY: usize = 5;
+
+// Make the roll the most recent one, forgetting previous rolls of the same expression
+fn push_last_roll(last_rolls: &mut VecDeque<LastRoll>, last_roll: LastRoll) {
+    last_rolls.retain(|roll| roll.expression() != last_roll.expression());
+    last_rolls.push_front(last_roll);
+    last_rolls.truncate(MAX_REROLL_HISTORY);
+}
+
+pub(crate) struct YearZeroRolls;
+impl TypeMapKey for YearZeroRolls {
+    // "user#channel", last Year Zero call and its dices, for `/push`
+    type Value = HashMap<String, (String, YzeRoll)>;
+}
+
+// A roll whose result is hidden until revealed
+pub(crate) struct SecretRoll {
+    // logged once revealed
+    entry: LogEntry,
+    crit: Option<HashSet<Critic>>,
+    // proves the result was not altered before being revealed
+    salt: String,
+    commitment: String,
+    // counted in the stats of the roller once revealed
+    rolls: Vec<(caith::Roller, RollResult)>,
+}
+
+pub(crate) struct SecretRolls;
+impl TypeMapKey for SecretRolls {
+    // "user#channel", secret rolls oldest first
+    type Value = HashMap<String, Vec<SecretRoll>>;
+}
+
+pub(crate) struct BlindRolls;
+impl TypeMapKey for BlindRolls {
+    // channel id, rolls only seen by the GM role, oldest first
+    type Value = HashMap<u64, Vec<SecretRoll>>;
+}
+
+fn user_channel_key(msg: &Message) -> String {
+    format!("{}#{}", msg.author.id, msg.channel_id)
 }
 
 fn get_roll_help_msg() -> String {
     "To get help, run `/help`".to_string()
 }
 
-async fn process_roll_str(input: &str, ctx: &Context, msg: &Message) -> Result<RollResult, String> {
+async fn process_roll_str(
+    input: &str,
+    ctx: &Context,
+    msg: &Message,
+    rng: &mut DiceRng,
+) -> Result<RollResult, String> {
     // TODO: once caith can save the parsed result, manage error on `new`
-    process_roll(caith::Roller::new(input).unwrap(), ctx, msg).await
+    process_roll(caith::Roller::new(input).unwrap(), ctx, msg, rng).await
+}
+
+fn roll_once(roller: &caith::Roller, rng: &mut DiceRng) -> Result<RollResult, String> {
+    roller.roll_with(rng).map_err(err_message)
+}
+
+// Keep the roll for `/reroll` and count it in the user's stats
+async fn remember_roll(
+    ctx: &Context,
+    msg: &Message,
+    roller: &caith::Roller,
+    res: &RollResult,
+    system: Option<&str>,
+) {
+    let mut data = ctx.data.write().await;
+    let all_data = data.get_mut::<Aliases>().unwrap();
+    all_data.record_stats(chat_id(msg), *msg.author.id.as_u64(), roller, res);
+
+    let reroll_table = data.get_mut::<RerollTable>().unwrap();
+    push_last_roll(
+        reroll_table.entry(user_channel_key(msg)).or_default(),
+        LastRoll::new(roller, res, system),
+    );
+
+    if let Some(call) = system {
+        if let Some(yze) = YzeRoll::from_call(call, res) {
+            let year_zero_rolls = data.get_mut::<YearZeroRolls>().unwrap();
+            year_zero_rolls.insert(user_channel_key(msg), (call.to_string(), yze));
+        }
+    }
 }
 
 async fn process_roll(
-    mut roller: caith::Roller,
+    roller: caith::Roller,
     ctx: &Context,
     msg: &Message,
+    rng: &mut DiceRng,
 ) -> Result<RollResult, String> {
-    match roller.roll() {
-        Ok(res) => {
-            {
-                // do not store comment for reroll
-                roller.trim_reason();
-                let mut data = ctx.data.write().await;
-                let reroll_table = data.get_mut::<RerollTable>().unwrap();
-                reroll_table.insert(msg.author.to_string(), roller);
-            }
-            Ok(res)
-        }
-        Err(err) => Err(err_message(err)),
+    let res = roll_once(&roller, rng)?;
+    remember_roll(ctx, msg, &roller, &res, None).await;
+    Ok(res)
+}
+
+// The RNG of a command: derived from the session's seed if a provably fair session is running
+async fn command_rng(ctx: &Context, msg: &Message) -> (DiceRng, Option<u64>) {
+    let mut data = ctx.data.write().await;
+    let all_data = data.get_mut::<Aliases>().unwrap();
+    match all_data.fair_roll(
+        chat_id(msg),
+        *msg.channel_id.as_u64(),
+        *msg.author.id.as_u64(),
+    ) {
+        Some((rng, nonce)) => (rng, Some(nonce)),
+        None => (default_rng(), None),
     }
 }
 
+async fn log_roll(
+    ctx: &Context,
+    msg: &Message,
+    expression: &str,
+    expansion: Option<String>,
+    result: &str,
+    crit: &Option<HashSet<Critic>>,
+    fair: Option<FairRoll>,
+) {
+    let entry = roll_entry(ctx, msg, expression, expansion, result, crit, fair).await;
+    log_entry(ctx, msg, entry).await;
+}
+
+async fn roll_entry(
+    ctx: &Context,
+    msg: &Message,
+    expression: &str,
+    expansion: Option<String>,
+    result: &str,
+    crit: &Option<HashSet<Critic>>,
+    fair: Option<FairRoll>,
+) -> LogEntry {
+    let user_name = get_user_name(ctx, msg).await;
+    let mut entry = LogEntry::new(*msg.author.id.as_u64(), &user_name, expression, result);
+    entry.expansion = expansion;
+    entry.fair = fair;
+    if let Some(crit) = crit {
+        entry.crit_min = crit.contains(&Critic::Min);
+        entry.crit_max = crit.contains(&Critic::Max);
+    }
+    entry
+}
+
+async fn log_entry(ctx: &Context, msg: &Message, entry: LogEntry) {
+    let mut data = ctx.data.write().await;
+    let all_data = data.get_mut::<Aliases>().unwrap();
+    all_data.log_roll(chat_id(msg), *msg.channel_id.as_u64(), entry);
+}
+
 async fn react_to(ctx: &Context, msg: &Message, crit: Option<HashSet<Critic>>) -> CommandResult {
     if let Some(crit) = crit {
         for c in crit.iter() {
@@ -82,12 +266,63 @@ async fn react_to(ctx: &Context, msg: &Message, crit: Option<HashSet<Critic>>) -
 }
 
 #[command]
+#[sub_commands(selftest)]
 /// Return the running version of Dìsle
 async fn disle(ctx: &Context, msg: &Message, _args: Args) -> CommandResult {
     super::send_message(ctx, msg, std::env!("CARGO_PKG_VERSION")).await?;
     Ok(())
 }
 
+const MAX_SELFTEST_ROLLS: u32 = 1_000_000;
+
+#[command]
+#[owners_only]
+/// ```
+/// /disle selftest <expression> <number of rolls>
+///
+/// Roll the expression many times and check that each dice size is fair with a
+/// chi-squared test. A p-value below 0.01 suggests a bias.
+/// Use plain dices: options like keep or explode change the distribution of the faces.
+///
+/// Ex: `/disle selftest 1d20 + 2d6 100000`
+/// ```
+async fn selftest(ctx: &Context, msg: &Message, args: Args) -> CommandResult {
+    let input = args.rest().trim();
+    let parsed = input
+        .rfind(' ')
+        .and_then(|idx| Some((&input[..idx], input[idx + 1..].parse::<u32>().ok()?)));
+    let msg_to_send = match parsed {
+        Some((expr, nb)) if nb > 0 && nb <= MAX_SELFTEST_ROLLS => {
+            let roller = caith::Roller::new(expr.trim()).unwrap();
+            let report = tokio::task::spawn_blocking(move || {
+                let mut stats = UserStats::default();
+                let mut rng = default_rng();
+                for _ in 0..nb {
+                    stats.add_roll(&roller, &roll_once(&roller, &mut rng)?);
+                }
+                Ok::<_, String>(stats.fairness_report())
+            })
+            .await?;
+            match report {
+                Ok(report) => format!(
+                    "Self-test of `{}` over {} rolls:\n{}",
+                    expr.trim(),
+                    nb,
+                    report
+                ),
+                Err(err) => err,
+            }
+        }
+        Some(_) => format!(
+            "The number of rolls must be between 1 and {}",
+            MAX_SELFTEST_ROLLS
+        ),
+        None => "Bad parameter: `/disle selftest <expression> <number of rolls>`".to_string(),
+    };
+    send_message(ctx, msg, &msg_to_send).await?;
+    Ok(())
+}
+
 #[command]
 #[aliases("r")]
 /// ```
@@ -125,151 +360,1341 @@ async fn disle(ctx: &Context, msg: &Message, _args: Args) -> CommandResult {
 ///     Sorted repeatition:
 ///     with the `^#` operator, the roll will be repeated and sorted by total.
 ///
-///     OVA roll:
-///     positive: `ova(12)` or negative: `ova(-5)`
+///     Multiple rolls:
+///     separate expressions with `;` to roll them at once: `1d20 + 5 ; 2d6 + 3 : longsword`
 ///
-///     Chronique de l'étrange roll: `cde(4, fire)`
+///     Game systems:
+///     rules of some games, ex: `ova(-5)` or `cde(4, fire)`, see `/help systems` for the list
 ///
 ///     Reason:
 ///     :   : Any text after `:` will be a comment"
 /// ```
-async fn roll(ctx: &Context, msg: &Message, args: Args) -> CommandResult {
+async fn roll(ctx: &Context, msg: &Message, mut args: Args) -> CommandResult {
+    if args.current() == Some("s") {
+        args.advance();
+        return secret_roll(ctx, msg, args).await;
+    }
     if args.is_empty() {
         if let Err(e) = msg.channel_id.say(&ctx.http, get_roll_help_msg()).await {
             eprintln!("Error sending message: {:?}", e);
         }
     } else {
-        let (msg_to_send, crit) = parse_args_and_roll(ctx, msg, args).await;
-
-        let sent_msg = send_message(ctx, msg, &msg_to_send).await?;
-        react_to(ctx, &sent_msg, crit).await?;
+        let input = args.rest().to_string();
+        let (mut rng, nonce) = command_rng(ctx, msg).await;
+        match parse_args_and_roll(ctx, msg, args, &mut rng).await {
+            Ok(reply) => {
+                if reply.segments.iter().any(|segment| segment.is_ok()) {
+                    remember_reply(ctx, msg, &reply).await;
+                    let fair = nonce.map(|nonce| FairRoll {
+                        nonce,
+                        input: reply.input.clone(),
+                    });
+                    log_roll(
+                        ctx,
+                        msg,
+                        &input,
+                        reply.alias_expansion.clone(),
+                        &reply.results(),
+                        &reply.crit,
+                        fair,
+                    )
+                    .await;
+                }
+                let format = {
+                    let data = ctx.data.read().await;
+                    let all_data = data.get::<Aliases>().unwrap();
+                    all_data.output_format(chat_id(msg), *msg.author.id.as_u64())
+                };
+                let sent_msg = match format {
+                    OutputFormat::Text => send_message(ctx, msg, &reply.to_string()).await?,
+                    OutputFormat::Embed => {
+                        let user_name = get_user_name(ctx, msg).await;
+                        send_embed(ctx, msg, |e| reply.to_embed(e, &user_name)).await?
+                    }
+                };
+                react_to(ctx, &sent_msg, reply.crit).await?;
+            }
+            Err(err) => {
+                send_message(ctx, msg, &err).await?;
+            }
+        }
     }
     Ok(())
 }
 
-async fn parse_args_and_roll(
+/// Roll the input as `/roll` does, for the commands relying on a game system
+pub(crate) async fn roll_call(ctx: &Context, msg: &Message, input: &str) -> CommandResult {
+    roll(ctx, msg, Args::new(input, &[Delimiter::Single(' ')])).await
+}
+
+#[command]
+#[aliases("gmr")]
+/// ```
+/// /gmroll <expression> (or /roll s <expression>)
+///
+/// Roll secretly: the result is sent to you as a private message and the channel only gets
+/// a proof (a hash of the result). Publish it later with `/reveal roll`.
+///
+/// Ex: `/gmroll 1d20 + 4 : stealth`
+/// ```
+async fn gmroll(ctx: &Context, msg: &Message, args: Args) -> CommandResult {
+    secret_roll(ctx, msg, args).await
+}
+
+// Roll the expression and commit to its result, which is not logged nor counted in the stats
+// until revealed. The roll is not kept for `/reroll` or `/push`, which would show its result.
+// `None` when nothing could be rolled, the user has been told why.
+async fn roll_hidden(
     ctx: &Context,
     msg: &Message,
     args: Args,
-) -> (String, Option<HashSet<Critic>>) {
-    if args.rest().starts_with("help") {
-        return (get_roll_help_msg(), None);
+) -> Result<Option<(String, SecretRoll)>, CommandError> {
+    if args.is_empty() {
+        send_message(ctx, msg, &get_roll_help_msg()).await?;
+        return Ok(None);
     }
+    let expression = args.rest().to_string();
+    let (mut rng, nonce) = command_rng(ctx, msg).await;
+    let reply = match parse_args_and_roll(ctx, msg, args, &mut rng).await {
+        Ok(reply) if reply.segments.iter().any(|segment| segment.is_ok()) => reply,
+        Ok(reply) => {
+            send_message(ctx, msg, &reply.to_string()).await?;
+            return Ok(None);
+        }
+        Err(err) => {
+            send_message(ctx, msg, &err).await?;
+            return Ok(None);
+        }
+    };
 
-    let input = parse_alias(ctx, msg, args).await;
-    let (input, has_alias) = match input {
-        Ok(input) => input,
-        Err(err) => return (err, None),
+    let result = reply.results();
+    let (salt, commitment) = commit(&result);
+    let fair = nonce.map(|nonce| FairRoll {
+        nonce,
+        input: reply.input.clone(),
+    });
+    let entry = roll_entry(
+        ctx,
+        msg,
+        &expression,
+        reply.alias_expansion.clone(),
+        &result,
+        &reply.crit,
+        fair,
+    )
+    .await;
+    let rolls = reply
+        .segments
+        .iter()
+        .flatten()
+        .map(|segment| (segment.roller.clone(), segment.roll.clone()))
+        .collect();
+    let secret = SecretRoll {
+        entry,
+        crit: reply.crit.clone(),
+        salt,
+        commitment,
+        rolls,
     };
+    Ok(Some((reply.to_string(), secret)))
+}
 
-    let alias_expansion = if has_alias {
-        Cow::Owned(format!("Alias expansion: `{}`\n", input))
+async fn channel_name(ctx: &Context, msg: &Message) -> String {
+    match msg.channel(&ctx.cache).await {
+        Some(Channel::Guild(channel)) => channel.name,
+        Some(_) | None => "Unknown channel".to_string(),
+    }
+}
+
+async fn secret_roll(ctx: &Context, msg: &Message, args: Args) -> CommandResult {
+    let (reply, secret) = match roll_hidden(ctx, msg, args).await? {
+        Some(rolled) => rolled,
+        None => return Ok(()),
+    };
+    let channel_name = channel_name(ctx, msg).await;
+    msg.author
+        .direct_message(&ctx, |m| {
+            m.content(format!(
+                "Your secret roll from `#{}`: {}",
+                channel_name, reply
+            ))
+        })
+        .await?;
+    let msg_to_send = format!(
+        "{} rolled secretly, proof: `{}`",
+        secret.entry.user_name, secret.commitment
+    );
+    {
+        let mut data = ctx.data.write().await;
+        let secrets = data.get_mut::<SecretRolls>().unwrap();
+        secrets
+            .entry(user_channel_key(msg))
+            .or_default()
+            .push(secret);
+    }
+    send_message(ctx, msg, &msg_to_send).await?;
+    Ok(())
+}
+
+// Discord sends the members of a guild by pages of at most 1000
+const MEMBERS_PAGE: u64 = 1000;
+
+// Members having the role. Listing the members of a guild requires the "Server Members Intent"
+// to be enabled for the bot in the Discord developer portal.
+async fn role_members(
+    ctx: &Context,
+    guild_id: GuildId,
+    role: RoleId,
+) -> serenity::Result<Vec<Member>> {
+    let mut members = Vec::new();
+    let mut after = None;
+    loop {
+        let page = guild_id
+            .members(&ctx.http, Some(MEMBERS_PAGE), after)
+            .await?;
+        let last_page = (page.len() as u64) < MEMBERS_PAGE;
+        after = page.last().map(|member| member.user.id);
+        members.extend(
+            page.into_iter()
+                .filter(|member| member.roles.contains(&role)),
+        );
+        if last_page {
+            return Ok(members);
+        }
+    }
+}
+
+#[command]
+#[aliases("br")]
+#[only_in(guilds)]
+/// ```
+/// /blindroll <expression> (or /br)
+///
+/// Roll blindly: the result is only sent to the members of the GM role (see `/gmrole`) as a
+/// private message, you only see a proof (a hash of the result). The GM publishes it later
+/// with `/reveal blind`.
+///
+/// Ex: `/blindroll 1d20 + 2 : perception`
+/// ```
+async fn blindroll(ctx: &Context, msg: &Message, args: Args) -> CommandResult {
+    let gm_role = {
+        let data = ctx.data.read().await;
+        let all_data = data.get::<Aliases>().unwrap();
+        all_data.gm_role(chat_id(msg))
+    };
+    let (guild_id, gm_role) = match (msg.guild_id, gm_role) {
+        (Some(guild_id), Some(gm_role)) => (guild_id, RoleId(gm_role)),
+        _ => {
+            send_message(ctx, msg, "No GM role set, see `/gmrole`").await?;
+            return Ok(());
+        }
+    };
+    let gms = match role_members(ctx, guild_id, gm_role).await {
+        Ok(gms) => gms,
+        Err(e) => {
+            eprintln!("Error listing the members of {}: {}", guild_id, e);
+            let msg_to_send = "Can't find the GMs, the bot needs the \"Server Members Intent\"";
+            send_message(ctx, msg, msg_to_send).await?;
+            return Ok(());
+        }
+    };
+    if gms.is_empty() {
+        send_message(ctx, msg, "Nobody has the GM role").await?;
+        return Ok(());
+    }
+
+    let (reply, secret) = match roll_hidden(ctx, msg, args).await? {
+        Some(rolled) => rolled,
+        None => return Ok(()),
+    };
+    let channel_name = channel_name(ctx, msg).await;
+    let content = format!(
+        "Blind roll of {} in `#{}`: {}",
+        secret.entry.user_name, channel_name, reply
+    );
+    for gm in gms.iter() {
+        if let Err(e) = gm.user.direct_message(&ctx, |m| m.content(&content)).await {
+            eprintln!("Error sending blind roll to {}: {}", gm.user.name, e);
+        }
+    }
+    let msg_to_send = format!(
+        "{} rolled blindly, only the GM knows the result, proof: `{}`",
+        secret.entry.user_name, secret.commitment
+    );
+    {
+        let mut data = ctx.data.write().await;
+        let blind_rolls = data.get_mut::<BlindRolls>().unwrap();
+        blind_rolls
+            .entry(*msg.channel_id.as_u64())
+            .or_default()
+            .push(secret);
+    }
+    send_message(ctx, msg, &msg_to_send).await?;
+    Ok(())
+}
+
+// Log the rolls and publish them with what is needed to check them
+async fn publish_rolls(ctx: &Context, msg: &Message, secrets: Vec<SecretRoll>) -> CommandResult {
+    let mut msg_to_send = String::new();
+    let mut crit: Option<HashSet<Critic>> = None;
+    for secret in secrets {
+        let check = if check_commitment(&secret.entry.result, &secret.salt, &secret.commitment) {
+            "✅"
+        } else {
+            "❌"
+        };
+        msg_to_send.push_str(&format!(
+            "Hidden roll of {} `{}`: {}\nProof: SHA-256(`{}:<result>`) = `{}` {}\n",
+            secret.entry.user_name,
+            secret.entry.expression,
+            secret.entry.result.trim_end(),
+            secret.salt,
+            secret.commitment,
+            check
+        ));
+        {
+            let mut data = ctx.data.write().await;
+            let all_data = data.get_mut::<Aliases>().unwrap();
+            for (roller, res) in secret.rolls.iter() {
+                all_data.record_stats(chat_id(msg), secret.entry.user_id, roller, res);
+            }
+        }
+        log_entry(ctx, msg, secret.entry).await;
+        if let Some(set) = secret.crit {
+            crit.get_or_insert_with(HashSet::new).extend(set);
+        }
+    }
+    let sent_msg = send_message(ctx, msg, &msg_to_send).await?;
+    react_to(ctx, &sent_msg, crit).await?;
+    Ok(())
+}
+
+// Publish the secret rolls of the user in the channel
+async fn reveal_rolls(ctx: &Context, msg: &Message) -> CommandResult {
+    let secrets = {
+        let mut data = ctx.data.write().await;
+        let secrets = data.get_mut::<SecretRolls>().unwrap();
+        secrets.remove(&user_channel_key(msg)).unwrap_or_default()
+    };
+    if secrets.is_empty() {
+        send_message(ctx, msg, "You don't have any secret roll").await?;
+        return Ok(());
+    }
+    publish_rolls(ctx, msg, secrets).await
+}
+
+// Publish the blind rolls of the channel, only for the GM role
+async fn reveal_blind_rolls(ctx: &Context, msg: &Message) -> CommandResult {
+    let gm_role = {
+        let data = ctx.data.read().await;
+        let all_data = data.get::<Aliases>().unwrap();
+        all_data.gm_role(chat_id(msg))
+    };
+    let is_gm = match (msg.guild_id, gm_role) {
+        (Some(guild_id), Some(gm_role)) => msg
+            .author
+            .has_role(&ctx.http, guild_id, RoleId(gm_role))
+            .await
+            .unwrap_or(false),
+        _ => false,
+    };
+    if !is_gm {
+        send_message(ctx, msg, "Only the GM role can reveal blind rolls").await?;
+        return Ok(());
+    }
+    let secrets = {
+        let mut data = ctx.data.write().await;
+        let blind_rolls = data.get_mut::<BlindRolls>().unwrap();
+        blind_rolls
+            .remove(msg.channel_id.as_u64())
+            .unwrap_or_default()
+    };
+    if secrets.is_empty() {
+        send_message(ctx, msg, "No blind roll in this channel").await?;
+        return Ok(());
+    }
+    publish_rolls(ctx, msg, secrets).await
+}
+
+#[command]
+#[aliases("rev")]
+/// ```
+/// /reveal (or /rev) [roll | blind]
+///
+/// Reveal your secret draw, with `roll` your secret rolls, or with `blind` the blind rolls of
+/// the channel (GM role only).
+/// ```
+async fn reveal(ctx: &Context, msg: &Message, args: Args) -> CommandResult {
+    match args.rest().trim() {
+        "roll" => reveal_rolls(ctx, msg).await,
+        "blind" => reveal_blind_rolls(ctx, msg).await,
+        #[cfg(feature = "cards")]
+        _ => super::cards_cmd::reveal_draw(ctx, msg).await,
+        #[cfg(not(feature = "cards"))]
+        _ => reveal_rolls(ctx, msg).await,
+    }
+}
+
+#[command]
+#[only_in(guilds)]
+/// ```
+/// /gmrole <role | none>
+///
+/// Set the role receiving the results of `/blindroll` (requires the alias management role).
+///
+/// Ex: `/gmrole @GM`
+/// ```
+async fn gmrole(ctx: &Context, msg: &Message, args: Args) -> CommandResult {
+    let arg = args.rest().trim();
+    let role = if arg == "none" {
+        Ok(None)
     } else {
-        Cow::Borrowed("")
+        arg.parse::<RoleId>()
+            .map(|role| Some(*role.as_u64()))
+            .map_err(|_| "Bad parameter: `/gmrole <role | none>`".to_string())
+    };
+    let msg_to_send = match role {
+        Ok(role) => {
+            if !is_allowed(ctx, msg).await {
+                "Only allowed users can set the GM role".to_string()
+            } else {
+                let mut data = ctx.data.write().await;
+                let all_data = data.get_mut::<Aliases>().unwrap();
+                all_data.set_gm_role(chat_id(msg), role)
+            }
+        }
+        Err(err) => err,
     };
+    send_message(ctx, msg, &msg_to_send).await?;
+    Ok(())
+}
 
-    match parse_interpreter(&input) {
-        Ok((input, interpreter)) => match process_roll_str(&input, ctx, msg).await {
-            Ok(res) => {
-                let crit_set = match interpreter {
-                    Interpreter::None => crate::search_crit(&res),
-                    _ => Ok(HashSet::new()),
+#[command]
+/// ```
+/// /format [guild] text|embed
+///
+/// Choose how your rolls are displayed: as plain text or as an embed.
+/// With `guild`, set the default for everyone (requires the alias management role).
+/// ```
+async fn format(ctx: &Context, msg: &Message, mut args: Args) -> CommandResult {
+    let for_guild = args.current() == Some("guild");
+    if for_guild {
+        args.advance();
+    }
+    let msg_to_send = match args.rest().parse::<OutputFormat>() {
+        Ok(format) => {
+            if for_guild && !is_allowed(ctx, msg).await {
+                "Only allowed users can change the default format".to_string()
+            } else {
+                let user_id = if for_guild {
+                    None
+                } else {
+                    Some(*msg.author.id.as_u64())
                 };
+                let mut data = ctx.data.write().await;
+                let all_data = data.get_mut::<Aliases>().unwrap();
+                all_data.set_output_format(format, chat_id(msg), user_id)
+            }
+        }
+        Err(err) => err,
+    };
+    send_message(ctx, msg, &msg_to_send).await?;
+    Ok(())
+}
 
-                let sep = if res.as_repeated().is_some() {
-                    "\n"
-                } else {
-                    ""
+// One expression of a `/roll` command, once rolled
+struct RolledSegment {
+    label: String,
+    // `None` when the interpreter doesn't give back a `RollResult`
+    res: Option<RollResult>,
+    text: String,
+    // the roll before interpretation, for `/reroll` and the stats
+    roller: caith::Roller,
+    roll: RollResult,
+}
+
+impl RolledSegment {
+    // the game system call of the segment, if any
+    fn system(&self) -> Option<&str> {
+        crate::systems::parse_call(&self.label).map(|_| self.label.as_str())
+    }
+}
+
+// Everything needed to answer a `/roll` command
+struct RollReply {
+    // the expressions that were rolled, joined with ` ; `
+    input: String,
+    alias_expansion: Option<String>,
+    segments: Vec<Result<RolledSegment, String>>,
+    crit: Option<HashSet<Critic>>,
+}
+
+impl Display for RollReply {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self.segments.as_slice() {
+            [Err(err)] => write!(f, "{}", err),
+            _ => {
+                if let Some(alias_expansion) = &self.alias_expansion {
+                    writeln!(f, "Alias expansion: `{}`", alias_expansion)?;
+                }
+                write!(f, "{}", self.results())
+            }
+        }
+    }
+}
+
+// Discord refuses embeds with more fields
+const MAX_EMBED_FIELDS: usize = 25;
+
+impl RollReply {
+    // the rolled segments, without the alias expansion
+    fn results(&self) -> String {
+        match self.segments.as_slice() {
+            [Ok(segment)] => segment.text.clone(),
+            segments => segments.iter().fold(String::new(), |mut s, segment| {
+                match segment {
+                    Ok(segment) => s.push_str(&format!("`{}`: {}\n", segment.label, segment.text)),
+                    Err(err) => s.push_str(&format!("{}\n", err)),
+                }
+                s
+            }),
+        }
+    }
+
+    fn to_embed<'a>(&self, e: &'a mut CreateEmbed, user_name: &str) -> &'a mut CreateEmbed {
+        match self.segments.as_slice() {
+            [Ok(segment)] => {
+                let reason = segment.res.as_ref().and_then(|res| res.get_reason());
+                match reason {
+                    Some(reason) => e.title(format!("{} rolls: {}", user_name, reason)),
+                    None => e.title(format!("{} rolls `{}`", user_name, segment.label)),
                 };
-                let res = match interpreter {
-                    Interpreter::None => res.to_string(),
-                    Interpreter::Ova(number) => match caith::helpers::compute_ova(&res, number) {
-                        Ok(roll_res) => roll_res.to_string(),
-                        Err(err) => return (err.to_string(), None),
-                    },
-                    Interpreter::Cde(element) => match caith::helpers::compute_cde(&res, element) {
-                        Ok(res) => res.to_string(),
-                        Err(_err) => {
-                            return (
-                                "Syntax error, expected: `cde(number_of_dice, element)`"
-                                    .to_string(),
-                                None,
-                            )
+                match segment.res.as_ref().map(|res| res.get_result()) {
+                    Some(RollResultType::Single(res)) => {
+                        e.description(format!("`{}`", res.to_string_history()));
+                        e.field("Total", format!("**{}**", res.get_total()), false);
+                    }
+                    Some(RollResultType::Repeated(res)) => {
+                        if res.len() < MAX_EMBED_FIELDS {
+                            res.iter().enumerate().for_each(|(i, r)| {
+                                e.field(format!("#{}", i + 1), r.to_string(true), true);
+                            });
+                        } else {
+                            e.description(res.iter().fold(String::new(), |mut s, r| {
+                                s.push_str(&r.to_string(true));
+                                s.push('\n');
+                                s
+                            }));
                         }
-                    },
-                };
-                (
-                    format!("{}{}{}", alias_expansion, sep, res),
-                    crate::process_crit(crit_set),
-                )
+                        if let Some(total) = res.get_total() {
+                            e.field("Total", format!("**{}**", total), false);
+                        }
+                    }
+                    None => {
+                        e.description(&segment.text);
+                    }
+                }
+            }
+            [Err(err)] => {
+                e.title(format!("{} rolls", user_name)).description(err);
+            }
+            segments => {
+                e.title(format!("{} rolls", user_name));
+                segments
+                    .iter()
+                    .take(MAX_EMBED_FIELDS)
+                    .for_each(|segment| match segment {
+                        Ok(segment) => {
+                            e.field(&segment.label, &segment.text, false);
+                        }
+                        Err(err) => {
+                            e.field("Error", err, false);
+                        }
+                    });
             }
-            Err(mut msg) => {
-                msg.insert_str(msg.len() - 4, ", or an alias");
-                (msg, None)
+        }
+
+        let colour = match &self.crit {
+            Some(crit) if crit.contains(&Critic::Min) && crit.contains(&Critic::Max) => {
+                Colour::ORANGE
+            }
+            Some(crit) if crit.contains(&Critic::Max) => Colour::DARK_GREEN,
+            Some(crit) if crit.contains(&Critic::Min) => Colour::RED,
+            Some(_) => Colour::LIGHT_GREY,
+            None => Colour::BLURPLE,
+        };
+        e.colour(colour);
+
+        if let Some(alias_expansion) = &self.alias_expansion {
+            e.footer(|f| f.text(format!("Alias expansion: {}", alias_expansion)));
+        }
+        e
+    }
+}
+
+async fn parse_args_and_roll(
+    ctx: &Context,
+    msg: &Message,
+    args: Args,
+    rng: &mut DiceRng,
+) -> Result<RollReply, String> {
+    if args.rest().starts_with("help") {
+        return Err(get_roll_help_msg());
+    }
+
+    // expand each segment separately so a comment only applies to its own segment
+    let mut expansions = Vec::new();
+    let mut has_alias = false;
+    for segment in split_segments(args.rest()) {
+        let (expanded, alias) = parse_alias(ctx, msg, segment).await?;
+        has_alias |= alias;
+        expansions.push(expanded);
+    }
+
+    let alias_expansion = if has_alias {
+        Some(expansions.join(" ; "))
+    } else {
+        None
+    };
+
+    // an alias body can contain several segments as well
+    let segments: Vec<&str> = expansions.iter().flat_map(|e| split_segments(e)).collect();
+    if segments.is_empty() {
+        return Err(get_roll_help_msg());
+    }
+
+    Ok(roll_segments(&segments, alias_expansion, rng))
+}
+
+// Keep the rolled segments of a public roll for `/reroll` and the stats
+async fn remember_reply(ctx: &Context, msg: &Message, reply: &RollReply) {
+    for segment in reply.segments.iter().flatten() {
+        remember_roll(ctx, msg, &segment.roller, &segment.roll, segment.system()).await;
+    }
+}
+
+// Roll the segments one after the other with the same RNG
+fn roll_segments(
+    segments: &[&str],
+    alias_expansion: Option<String>,
+    rng: &mut DiceRng,
+) -> RollReply {
+    let mut crit: Option<HashSet<Critic>> = None;
+    let mut rolled = Vec::with_capacity(segments.len());
+    for segment in segments {
+        rolled.push(match roll_segment(segment, rng) {
+            Ok((res, crit_set)) => {
+                if let Some(set) = crate::process_crit(crit_set) {
+                    crit.get_or_insert_with(HashSet::new).extend(set);
+                }
+                Ok(res)
             }
+            Err(err) => Err(err),
+        });
+    }
+
+    RollReply {
+        input: segments.join(" ; "),
+        alias_expansion,
+        segments: rolled,
+        crit,
+    }
+}
+
+/// Roll again the expression of a fair roll with its RNG, to compare with the logged result
+pub(crate) fn replay_roll(expression: &str, fair: &FairRoll, rng: &mut DiceRng) -> String {
+    let single = expression.starts_with("/reroll") || expression.starts_with("/push");
+    if single && crate::systems::parse_call(&fair.input).is_none() {
+        // `/reroll`, `/reroll_dice` and `/push` roll a single expression
+        match roll_once(&caith::Roller::new(&fair.input).unwrap(), rng) {
+            Ok(res) => res.to_string(),
+            Err(err) => err,
+        }
+    } else {
+        let segments: Vec<&str> = split_segments(&fair.input).collect();
+        roll_segments(&segments, None, rng).results()
+    }
+}
+
+// Split a roll command into its `;` separated expressions
+fn split_segments(input: &str) -> impl Iterator<Item = &str> {
+    input.split(';').map(str::trim).filter(|s| !s.is_empty())
+}
+
+// The expression of a segment, without its reason
+fn segment_label(segment: &str) -> &str {
+    match segment.find(':') {
+        Some(idx) => segment[..idx].trim(),
+        None => segment,
+    }
+}
+
+// Error of a segment's roll: a segment can also be an alias, which wasn't found
+fn segment_error(err: caith::RollError) -> String {
+    match err {
+        caith::RollError::ParseError(_) => format!("Error:\n```\n{}, or an alias\n```", err),
+        caith::RollError::ParamError(_) => err_message(err),
+    }
+}
+
+fn roll_segment(
+    input: &str,
+    rng: &mut DiceRng,
+) -> Result<(RolledSegment, Result<HashSet<Critic>, serenity::Error>), String> {
+    let label = segment_label(input).to_string();
+    let system = crate::systems::parse_call(&label);
+    let input = match system {
+        Some((system, params)) => {
+            // keep the reason so caith displays it
+            let reason = input.find(':').map(|idx| &input[idx..]).unwrap_or_default();
+            format!("{} {}", system.expression(params)?, reason)
+        }
+        None => input.to_string(),
+    };
+    // TODO: once caith can save the parsed result, manage error on `new`
+    let roller = caith::Roller::new(&input).unwrap();
+    let res = roller.roll_with(rng).map_err(segment_error)?;
+    let roll = res.clone();
+    let sep = if res.as_repeated().is_some() {
+        "\n"
+    } else {
+        ""
+    };
+    let (crit_set, outcome) = match system {
+        Some((system, params)) => (
+            Ok(system.crit(params, &res)),
+            system.interpret(params, res)?,
+        ),
+        None => (crate::search_crit(&res), Outcome::new(res)),
+    };
+    Ok((
+        RolledSegment {
+            label,
+            res: outcome.res,
+            text: format!("{}{}", sep, outcome.text),
+            roller,
+            roll,
         },
+        crit_set,
+    ))
+}
+
+// Roll again a game system call, ex: a pushed roll of `coc`
+async fn reroll_system(
+    ctx: &Context,
+    msg: &Message,
+    call: &str,
+) -> (String, Option<HashSet<Critic>>) {
+    let (mut rng, nonce) = command_rng(ctx, msg).await;
+    match roll_segment(call, &mut rng) {
+        Ok((segment, crit_set)) => {
+            remember_roll(ctx, msg, &segment.roller, &segment.roll, Some(call)).await;
+            let crit = crate::process_crit(crit_set);
+            let fair = nonce.map(|nonce| FairRoll {
+                nonce,
+                input: call.to_string(),
+            });
+            let expression = format!("/reroll {}", call);
+            log_roll(ctx, msg, &expression, None, &segment.text, &crit, fair).await;
+            (format_reroll(call, &segment.text), crit)
+        }
         Err(err) => (err, None),
     }
 }
 
-fn parse_interpreter<'a>(input: &'a str) -> Result<(Cow<'a, str>, Interpreter), String> {
-    if input.starts_with("ova(") {
-        let number = input[4..input.len() - 1]
-            .trim()
-            .parse::<i32>()
-            .map_err(|e| e.to_string())?;
-        Ok((
-            Cow::Owned(format!("{}d6", number.abs())),
-            Interpreter::Ova(number),
-        ))
-    } else if input.starts_with("cde(") {
-        let comma = input.find(',').ok_or("")?;
-        let number = input[4..comma]
-            .trim()
-            .parse::<u32>()
-            .map_err(|_| "Syntax error, expected: `cde(number_of_dice, element)`".to_string())?;
-        let element = input[comma + 1..input.len() - 1].trim();
-        Ok((
-            Cow::Owned(format!("{}d10", number)),
-            Interpreter::Cde(element),
-        ))
+fn format_reroll(expression: &str, res: &str) -> String {
+    format!("reroll `{}`: {}", expression, res)
+}
+
+// The dice rerolled by `/reroll_dice` without parameter
+fn first_dice(roller: &caith::Roller) -> Result<String, String> {
+    match roller.dices() {
+        Ok(mut dices) => match dices.next() {
+            Some(dice) => Ok(dice),
+            _ => Err("No dice to reroll".to_string()),
+        },
+        Err(e) => Err(e.to_string()),
+    }
+}
+
+// Which dice of the last roll `/reroll_dice` rerolls
+#[derive(Debug, PartialEq)]
+enum DiceSelection {
+    // position of the dice in the roll, from 1
+    Index(usize),
+    // every dice with this value
+    Value(u64),
+    Lowest,
+}
+
+impl FromStr for DiceSelection {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.trim().to_lowercase();
+        if s == "lowest" {
+            Ok(DiceSelection::Lowest)
+        } else if let Some(value) = s.strip_suffix('s') {
+            value
+                .parse()
+                .map(DiceSelection::Value)
+                .map_err(|_| DICE_SELECTION_ERR.to_string())
+        } else {
+            match s.parse() {
+                Ok(0) | Err(_) => Err(DICE_SELECTION_ERR.to_string()),
+                Ok(idx) => Ok(DiceSelection::Index(idx)),
+            }
+        }
+    }
+}
+
+impl Display for DiceSelection {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            DiceSelection::Index(idx) => write!(f, "dice {}", idx),
+            DiceSelection::Value(value) => write!(f, "the {}s", value),
+            DiceSelection::Lowest => write!(f, "the lowest dice"),
+        }
+    }
+}
+
+const DICE_SELECTION_ERR: &str =
+    "Bad parameter, expected a dice position, a value followed by `s` (ex: `1s`) or `lowest`";
+
+// Positions of the selected dice, counted over all the dices of the roll
+fn select_dices(history: &[RollHistory], selection: &DiceSelection) -> Result<Vec<usize>, String> {
+    let dices: Vec<u64> = history
+        .iter()
+        .filter_map(|h| match h {
+            RollHistory::Roll(dices) => Some(dices),
+            _ => None,
+        })
+        .flatten()
+        .map(|dice| dice.res)
+        .collect();
+    let selected: Vec<usize> = match selection {
+        DiceSelection::Index(idx) if *idx <= dices.len() => vec![idx - 1],
+        DiceSelection::Index(_) => Vec::new(),
+        DiceSelection::Value(value) => (0..dices.len()).filter(|i| dices[*i] == *value).collect(),
+        DiceSelection::Lowest => (0..dices.len())
+            .min_by_key(|i| dices[*i])
+            .into_iter()
+            .collect(),
+    };
+    if selected.is_empty() {
+        Err(format!("No {} in the last roll", selection))
+    } else {
+        Ok(selected)
+    }
+}
+
+// Only dices without option can be rerolled one by one, the other ones change the total in ways
+// that can't be recomputed from the history
+fn check_plain_dices(expression: &str) -> Result<Vec<u64>, String> {
+    let plain = expression
+        .split(|c: char| "+-*/()^".contains(c))
+        .map(str::trim)
+        .all(|operand| {
+            match operand
+                .trim_start_matches(|c: char| c.is_ascii_digit())
+                .strip_prefix(['d', 'D'])
+            {
+                Some(side) => !side.is_empty() && side.chars().all(|c| c.is_ascii_digit()),
+                // anything else than a number is an option
+                None => operand.is_empty() || operand.parse::<f64>().is_ok(),
+            }
+        });
+    if plain {
+        Ok(dice_sides(expression))
+    } else {
+        Err("Only dices without option (like `3d6`) can be rerolled one by one".to_string())
+    }
+}
+
+// The expression giving the new result: kept dices become values, selected dices are rolled
+// again. Ex: `2d6 + 3` with the second dice selected becomes `(4 + 1d6) + 3`
+fn reroll_expression(
+    history: &[RollHistory],
+    sides: &[u64],
+    selected: &[usize],
+) -> Result<String, String> {
+    let mut expression = String::new();
+    let mut sides = sides.iter();
+    let mut idx = 0;
+    for h in history {
+        match h {
+            RollHistory::Roll(dices) => {
+                let side = sides
+                    .next()
+                    .ok_or("Can't find the dices of the last roll")?;
+                let terms: Vec<String> = dices
+                    .iter()
+                    .map(|dice| {
+                        idx += 1;
+                        if selected.contains(&(idx - 1)) {
+                            format!("1d{}", side)
+                        } else {
+                            dice.res.to_string()
+                        }
+                    })
+                    .collect();
+                if terms.len() == 1 {
+                    expression.push_str(&terms[0]);
+                } else {
+                    let _ = write!(expression, "({})", terms.join(" + "));
+                }
+            }
+            RollHistory::Fudge(_) => return Err("Fudge dices can't be rerolled".to_string()),
+            RollHistory::Value(value) => expression.push_str(&value.to_string()),
+            RollHistory::Separator(sep) => expression.push_str(sep),
+            RollHistory::OpenParenthesis => expression.push('('),
+            RollHistory::CloseParenthesis => expression.push(')'),
+            _ => return Err("Unsupported roll".to_string()),
+        }
+    }
+    Ok(expression)
+}
+
+// Put the rerolled values in the history, so the next `/reroll_dice` works on the new result
+fn update_history(
+    history: &mut [RollHistory],
+    sides: &[u64],
+    selected: &[usize],
+    rerolled: &RollResult,
+) {
+    let mut new_values = rerolled
+        .as_single()
+        .into_iter()
+        .flat_map(|res| res.get_history().iter())
+        .filter_map(|h| match h {
+            RollHistory::Roll(dices) => Some(dices),
+            _ => None,
+        })
+        .flatten()
+        .map(|dice| dice.res);
+    let mut idx = 0;
+    let groups = history.iter_mut().filter_map(|h| match h {
+        RollHistory::Roll(dices) => Some(dices),
+        _ => None,
+    });
+    for (dices, side) in groups.zip(sides.iter()) {
+        for dice in dices.iter_mut() {
+            if selected.contains(&idx) {
+                if let Some(value) = new_values.next() {
+                    *dice = DiceResult::new(value, *side);
+                }
+            }
+            idx += 1;
+        }
+    }
+}
+
+// Reroll the selected dices of the last roll, keeping the other ones
+async fn reroll_selected_dices(
+    ctx: &Context,
+    msg: &Message,
+    selection: DiceSelection,
+) -> Result<(String, Option<HashSet<Critic>>), String> {
+    let (expression, history) = {
+        let data = ctx.data.read().await;
+        let reroll_table = data.get::<RerollTable>().unwrap();
+        match reroll_table
+            .get(&user_channel_key(msg))
+            .and_then(|last_rolls| last_rolls.front())
+        {
+            Some(last_roll) => (
+                last_roll.roller.as_str().to_string(),
+                last_roll
+                    .history
+                    .clone()
+                    .ok_or("Dices of a repeated roll can't be rerolled one by one")?,
+            ),
+            None => return Err("No previous roll".to_string()),
+        }
+    };
+    let sides = check_plain_dices(&expression)?;
+    let selected = select_dices(&history, &selection)?;
+    let input = reroll_expression(&history, &sides, &selected)?;
+    let roller = caith::Roller::new(&input).map_err(err_message)?;
+
+    let (mut rng, nonce) = command_rng(ctx, msg).await;
+    let res = roll_once(&roller, &mut rng)?;
+    {
+        let mut data = ctx.data.write().await;
+        let all_data = data.get_mut::<Aliases>().unwrap();
+        all_data.record_stats(chat_id(msg), *msg.author.id.as_u64(), &roller, &res);
+        let reroll_table = data.get_mut::<RerollTable>().unwrap();
+        if let Some(last_roll) = reroll_table
+            .get_mut(&user_channel_key(msg))
+            .and_then(|last_rolls| last_rolls.front_mut())
+        {
+            if let Some(history) = last_roll.history.as_mut() {
+                update_history(history, &sides, &selected, &res);
+            }
+            last_roll.result = res.to_string();
+        }
+    }
+
+    let crit = crate::process_crit(crate::search_crit(&res));
+    let res = res.to_string();
+    let fair = nonce.map(|nonce| FairRoll {
+        nonce,
+        input: input.clone(),
+    });
+    log_roll(
+        ctx,
+        msg,
+        &format!("/reroll_dice {}", input),
+        None,
+        &res,
+        &crit,
+        fair,
+    )
+    .await;
+    Ok((format_selected_reroll(&selection, &expression, &res), crit))
+}
+
+// Success options of a dice expression, see `t` and `tt` in caith's syntax
+#[derive(Debug, Default, PartialEq)]
+struct Target {
+    min: Option<u64>,
+    values: Vec<u64>,
+    double: Option<u64>,
+}
+
+impl Target {
+    // `None` if the options have no target
+    fn parse(options: &str) -> Option<Target> {
+        let number = |s: &str| -> (Option<u64>, usize) {
+            let len = s.chars().take_while(|c| c.is_ascii_digit()).count();
+            (s[..len].parse().ok(), len)
+        };
+        let mut target = Target::default();
+        let mut found = false;
+        let mut rest = options;
+        while let Some(idx) = rest.find('t') {
+            rest = &rest[idx + 1..];
+            found = true;
+            if let Some(r) = rest.strip_prefix('t') {
+                let (n, len) = number(r);
+                target.double = n;
+                rest = &r[len..];
+            } else if let Some(r) = rest.strip_prefix('[') {
+                let end = r.find(']').unwrap_or(r.len());
+                target.values = r[..end]
+                    .split(',')
+                    .filter_map(|v| v.trim().parse().ok())
+                    .collect();
+                rest = &r[end..];
+            } else {
+                let (n, len) = number(rest);
+                target.min = n;
+                rest = &rest[len..];
+            }
+        }
+        found.then_some(target)
+    }
+
+    fn successes(&self, value: u64) -> u64 {
+        let success = self.min.is_some_and(|min| value >= min) || self.values.contains(&value);
+        let double = self.double.is_some_and(|double| value >= double);
+        success as u64 + double as u64
+    }
+}
+
+// The expression pushing the roll and the positions of the rerolled dices: dices which are not
+// a success are rolled again, successes are kept.
+// Ex: `5d6 t6` giving `[6, 5, 5, 4, 1]` becomes `(1 + 4d6 t6)`
+fn push_expression(
+    history: &[RollHistory],
+    terms: &[DiceTerm],
+) -> Result<(String, Vec<usize>), String> {
+    let no_target = || "Only rolls with a target (like `5d6 t6`) can be pushed".to_string();
+    // explosions and rerolls add dices to the history which can't be pushed one by one
+    if terms.iter().any(DiceTerm::rolls_again) {
+        return Err("Rolls with exploding or rerolled dices can't be pushed".to_string());
+    }
+    let mut expression = String::new();
+    let mut selected = Vec::new();
+    let mut terms = terms.iter();
+    let mut idx = 0;
+    for h in history {
+        match h {
+            RollHistory::Roll(dices) => {
+                let term = terms.next().ok_or_else(no_target)?;
+                let target = Target::parse(&term.options).ok_or_else(no_target)?;
+                let mut kept = 0;
+                let mut rerolled = 0;
+                for dice in dices.iter() {
+                    match target.successes(dice.res) {
+                        0 => {
+                            selected.push(idx);
+                            rerolled += 1;
+                        }
+                        successes => kept += successes,
+                    }
+                    idx += 1;
+                }
+                let dices = format!("{}d{}{}", rerolled, term.sides, term.options);
+                match (kept, rerolled) {
+                    (_, 0) => expression.push_str(&kept.to_string()),
+                    (0, _) => expression.push_str(&dices),
+                    _ => {
+                        let _ = write!(expression, "({} + {})", kept, dices);
+                    }
+                }
+            }
+            RollHistory::Fudge(_) => return Err(no_target()),
+            RollHistory::Value(value) => expression.push_str(&value.to_string()),
+            RollHistory::Separator(sep) => expression.push_str(sep),
+            RollHistory::OpenParenthesis => expression.push('('),
+            RollHistory::CloseParenthesis => expression.push(')'),
+            _ => return Err("Unsupported roll".to_string()),
+        }
+    }
+    if selected.is_empty() {
+        Err("All the dices are already a success, nothing to push".to_string())
     } else {
-        Ok((Cow::Borrowed(input), Interpreter::None))
+        Ok((expression, selected))
     }
 }
 
+// Reroll the dices of the last roll which are not a success, spending a point of the resource
+async fn push_roll(
+    ctx: &Context,
+    msg: &Message,
+    resource: &str,
+) -> Result<(String, Option<HashSet<Critic>>), String> {
+    let (expression, history, original) = {
+        let data = ctx.data.read().await;
+        let reroll_table = data.get::<RerollTable>().unwrap();
+        let last_roll = reroll_table
+            .get(&user_channel_key(msg))
+            .and_then(|last_rolls| last_rolls.front());
+        // a Year Zero roll is pushed with its own rules
+        let year_zero = data
+            .get::<YearZeroRolls>()
+            .unwrap()
+            .get(&user_channel_key(msg))
+            .filter(|(call, _)| last_roll.and_then(|l| l.system.as_ref()) == Some(call))
+            .cloned();
+        if let Some((call, yze)) = year_zero {
+            drop(data);
+            return push_year_zero(ctx, msg, resource, call, yze).await;
+        }
+        match last_roll {
+            Some(last_roll) => (
+                last_roll.roller.as_str().to_string(),
+                last_roll
+                    .history
+                    .clone()
+                    .ok_or("A repeated roll can't be pushed")?,
+                last_roll.result.clone(),
+            ),
+            None => return Err("No previous roll".to_string()),
+        }
+    };
+    let terms = dice_terms(&expression);
+    let (input, selected) = push_expression(&history, &terms)?;
+    let roller = caith::Roller::new(&input).map_err(err_message)?;
+
+    let (mut rng, nonce) = command_rng(ctx, msg).await;
+    let res = roll_once(&roller, &mut rng)?;
+    let left = spend_for_push(ctx, msg, resource).await?;
+    {
+        let mut data = ctx.data.write().await;
+        let all_data = data.get_mut::<Aliases>().unwrap();
+        all_data.record_stats(chat_id(msg), *msg.author.id.as_u64(), &roller, &res);
+        let reroll_table = data.get_mut::<RerollTable>().unwrap();
+        if let Some(last_roll) = reroll_table
+            .get_mut(&user_channel_key(msg))
+            .and_then(|last_rolls| last_rolls.front_mut())
+        {
+            let sides: Vec<u64> = terms.iter().map(|term| term.sides).collect();
+            if let Some(history) = last_roll.history.as_mut() {
+                update_history(history, &sides, &selected, &res);
+            }
+            last_roll.result = res.to_string();
+        }
+    }
+
+    let crit = crate::process_crit(crate::search_crit(&res));
+    let res = res.to_string();
+    let fair = nonce.map(|nonce| FairRoll {
+        nonce,
+        input: input.clone(),
+    });
+    log_roll(
+        ctx,
+        msg,
+        &format!("/push {}", input),
+        None,
+        &res,
+        &crit,
+        fair,
+    )
+    .await;
+    let spent = left.map(|left| (resource, left));
+    Ok((format_push(&expression, &original, &res, spent), crit))
+}
+
+// Spend one point of the resource if any, returns what is left. Only called once the push is
+// rolled, so a failed roll costs nothing.
+async fn spend_for_push(
+    ctx: &Context,
+    msg: &Message,
+    resource: &str,
+) -> Result<Option<u64>, String> {
+    if resource.is_empty() {
+        return Ok(None);
+    }
+    let mut data = ctx.data.write().await;
+    let all_data = data.get_mut::<Aliases>().unwrap();
+    all_data
+        .spend_resource(chat_id(msg), *msg.author.id.as_u64(), resource)
+        .map(Some)
+}
+
+// Year Zero push: every dice but the sixes and the banes is rolled again, one group per pool
+async fn push_year_zero(
+    ctx: &Context,
+    msg: &Message,
+    resource: &str,
+    call: String,
+    mut yze: YzeRoll,
+) -> Result<(String, Option<HashSet<Critic>>), String> {
+    let original = yze.to_string();
+    let input = yze.push_expression()?;
+    let roller = caith::Roller::new(&input).map_err(err_message)?;
+    let (mut rng, nonce) = command_rng(ctx, msg).await;
+    let res = roll_once(&roller, &mut rng)?;
+    yze.push(&res)
+        .ok_or_else(|| format!("Can't push the roll with `{}`", input))?;
+    let left = spend_for_push(ctx, msg, resource).await?;
+    let pushed = yze.to_string();
+    let consequences = yze.consequences();
+    {
+        let mut data = ctx.data.write().await;
+        let all_data = data.get_mut::<Aliases>().unwrap();
+        all_data.record_stats(chat_id(msg), *msg.author.id.as_u64(), &roller, &res);
+        let reroll_table = data.get_mut::<RerollTable>().unwrap();
+        if let Some(last_roll) = reroll_table
+            .get_mut(&user_channel_key(msg))
+            .and_then(|last_rolls| last_rolls.front_mut())
+        {
+            last_roll.result = pushed.clone();
+        }
+        let year_zero_rolls = data.get_mut::<YearZeroRolls>().unwrap();
+        year_zero_rolls.insert(user_channel_key(msg), (call.clone(), yze));
+    }
+
+    let fair = nonce.map(|nonce| FairRoll {
+        nonce,
+        input: input.clone(),
+    });
+    let expression = format!("/push {}", input);
+    log_roll(ctx, msg, &expression, None, &res.to_string(), &None, fair).await;
+    let spent = left.map(|left| (resource, left));
+    let mut s = format_push(&call, &original, &pushed, spent);
+    let _ = write!(s, "\n{}", consequences);
+    Ok((s, None))
+}
+
+fn format_push(
+    expression: &str,
+    original: &str,
+    pushed: &str,
+    spent: Option<(&str, u64)>,
+) -> String {
+    let mut s = format!("push `{}`: {} → {}", expression, original, pushed);
+    if let Some((resource, left)) = spent {
+        let _ = write!(s, "\n1 {} spent, {} left", resource, left);
+    }
+    s
+}
+
+fn format_selected_reroll(selection: &DiceSelection, expression: &str, res: &str) -> String {
+    format!("reroll {} of `{}`: {}", selection, expression, res)
+}
+
+#[command]
+/// ```
+/// /push [resource]
+///
+/// Push your last roll: the dices which are not a success (see the target option `t`) are
+/// rolled again and the successes are kept. With the name of a resource, one point of it is
+/// spent (see `/resource`). Secret and blind rolls can't be pushed, nor rolls with exploding or
+/// rerolled dices.
+/// A Year Zero roll (`yze`) keeps its sixes and its banes, and tells what the push costs.
+///
+/// Ex: `/r 5d6 t6` then `/push willpower`
+/// ```
+async fn push(ctx: &Context, msg: &Message, args: Args) -> CommandResult {
+    let resource = args.rest().trim().to_lowercase();
+    let (msg_to_send, crit) = push_roll(ctx, msg, &resource)
+        .await
+        .unwrap_or_else(|err| (err, None));
+    let sent_msg = send_message(ctx, msg, &msg_to_send).await?;
+    react_to(ctx, &sent_msg, crit).await?;
+    Ok(())
+}
+
 #[command]
 #[aliases("rr")]
 /// ```
-/// /reroll (or /rr)
+/// /reroll (or /rr) [n]
 ///
-/// Reroll the last roll of the user
+/// Reroll the last roll of the user in the channel, or the n-th last expression rolled.
+/// A game system call is rolled again with its rules, ex: pushing a `coc` roll.
+///
+/// Ex: `/rr 2` rerolls the expression rolled before the last one
 /// ```
 async fn reroll(ctx: &Context, msg: &Message, args: Args) -> CommandResult {
-    let input = args.rest();
-    let (msg_to_send, crit) = if input.starts_with("help") {
-        (get_roll_help_msg(), None)
-    } else {
-        let roller = {
-            let mut data = ctx.data.write().await;
-            let reroll_table = data.get_mut::<RerollTable>().unwrap();
-            reroll_table.remove(&msg.author.to_string())
-        };
-        match roller {
-            Some(roller) => {
-                let cmd = roller.as_str().to_string();
-                match process_roll(roller, ctx, msg).await {
-                    Ok(res) => {
-                        let crit_set = crate::search_crit(&res);
-                        (
-                            format!("reroll `{}`: {}", cmd, res),
-                            crate::process_crit(crit_set),
-                        )
+    let input = args.rest().trim();
+    let nth = match input {
+        "" => Ok(1),
+        _ => match input.parse::<usize>() {
+            Ok(nth) if (1..=MAX_REROLL_HISTORY).contains(&nth) => Ok(nth),
+            _ => Err(format!(
+                "Bad parameter, expected a number between 1 and {}",
+                MAX_REROLL_HISTORY
+            )),
+        },
+    };
+    let (msg_to_send, crit) = match nth {
+        _ if input.starts_with("help") => (get_roll_help_msg(), None),
+        Err(err) => (err, None),
+        Ok(nth) => {
+            let last_roll = {
+                let data = ctx.data.read().await;
+                let reroll_table = data.get::<RerollTable>().unwrap();
+                reroll_table
+                    .get(&user_channel_key(msg))
+                    .and_then(|last_rolls| last_rolls.get(nth - 1))
+                    .map(|last_roll| (last_roll.roller.clone(), last_roll.system.clone()))
+            };
+            match last_roll {
+                Some((_, Some(call))) => reroll_system(ctx, msg, &call).await,
+                Some((roller, None)) => {
+                    let cmd = roller.as_str().to_string();
+                    let (mut rng, nonce) = command_rng(ctx, msg).await;
+                    match process_roll(roller, ctx, msg, &mut rng).await {
+                        Ok(res) => {
+                            let crit = crate::process_crit(crate::search_crit(&res));
+                            let res = res.to_string();
+                            let fair = nonce.map(|nonce| FairRoll {
+                                nonce,
+                                input: cmd.clone(),
+                            });
+                            let expression = format!("/reroll {}", cmd);
+                            log_roll(ctx, msg, &expression, None, &res, &crit, fair).await;
+                            (format_reroll(&cmd, &res), crit)
+                        }
+                        Err(msg) => (msg, None),
                     }
-                    Err(msg) => (msg, None),
                 }
+                None => ("No previous roll".to_owned(), None),
             }
-            None => ("No previous roll".to_owned(), None),
         }
     };
 
@@ -281,43 +1706,65 @@ async fn reroll(ctx: &Context, msg: &Message, args: Args) -> CommandResult {
 #[command]
 #[aliases("rd")]
 /// ```
-/// /reroll_dice (or /rd)
+/// /reroll_dice (or /rd) [position | value`s` | lowest]
 ///
-/// Reroll the first dice of the last roll
+/// Without parameter, reroll the first dice expression of the last roll.
+/// Otherwise reroll only some dices of the last roll, keeping the other ones: the dice at the
+/// given position, all the dices with the given value, or the lowest dice.
+///
+/// Ex: `/rd 3`, `/rd 1s`, `/rd lowest`
 /// ```
 async fn reroll_dice(ctx: &Context, msg: &Message, args: Args) -> CommandResult {
     let input = args.rest();
     let (msg_to_send, crit) = if input.starts_with("help") {
         (get_roll_help_msg(), None)
+    } else if !input.trim().is_empty() {
+        match input.parse::<DiceSelection>() {
+            Ok(selection) => reroll_selected_dices(ctx, msg, selection)
+                .await
+                .unwrap_or_else(|err| (err, None)),
+            Err(err) => (err, None),
+        }
     } else {
         let dice = {
             let mut data = ctx.data.write().await;
             let roller = {
                 let reroll_table = data.get_mut::<RerollTable>().unwrap();
-                reroll_table.get(&msg.author.to_string())
+                reroll_table
+                    .get(&user_channel_key(msg))
+                    .and_then(|last_rolls| last_rolls.front())
             };
             match roller {
-                Some(roller) => match roller.dices() {
-                    Ok(mut dices) => match dices.next() {
-                        Some(dice) => Ok(dice),
-                        _ => Err("No dice to reroll".to_string()),
-                    },
-                    Err(e) => Err(e.to_string()),
-                },
+                Some(last_roll) => first_dice(&last_roll.roller),
                 None => Err("No previous roll".to_string()),
             }
         };
         match dice {
-            Ok(dice) => match process_roll_str(&dice, ctx, msg).await {
-                Ok(res) => {
-                    let crit_set = crate::search_crit(&res);
-                    (
-                        format!("reroll `{}`: {}", dice, res),
-                        crate::process_crit(crit_set),
-                    )
+            Ok(dice) => {
+                let (mut rng, nonce) = command_rng(ctx, msg).await;
+                match process_roll_str(&dice, ctx, msg, &mut rng).await {
+                    Ok(res) => {
+                        let crit = crate::process_crit(crate::search_crit(&res));
+                        let res = res.to_string();
+                        let fair = nonce.map(|nonce| FairRoll {
+                            nonce,
+                            input: dice.clone(),
+                        });
+                        log_roll(
+                            ctx,
+                            msg,
+                            &format!("/reroll_dice {}", dice),
+                            None,
+                            &res,
+                            &crit,
+                            fair,
+                        )
+                        .await;
+                        (format_reroll(&dice, &res), crit)
+                    }
+                    Err(e) => (e, None),
                 }
-                Err(e) => (e, None),
-            },
+            }
             Err(err) => (err, None),
         }
     };
@@ -326,3 +1773,336 @@ async fn reroll_dice(ctx: &Context, msg: &Message, args: Args) -> CommandResult
     react_to(ctx, &sent_msg, crit).await?;
     Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::rng::rng_from_seed;
+
+    fn roll(segments: &[&str], seed: u64) -> String {
+        roll_segments(segments, None, &mut rng_from_seed(seed)).to_string()
+    }
+
+    #[test]
+    fn roll_is_reproducible() {
+        assert_eq!(
+            "`[13] + 5` = **18**, Reason: `attack`",
+            roll(&["1d20 + 5 : attack"], 1)
+        );
+        assert_eq!(
+            "`1d20 + 5`: `[2] + 5` = **7**, Reason: `attack`\n\
+            `(2d6) ^ 2`: \n`[5, 4]` = **9**\n`[6, 1]` = **7**\n\n",
+            roll(&["1d20 + 5 : attack", "(2d6) ^ 2"], 2)
+        );
+        assert_eq!(
+            "`10d10 t8`: `[8, 8, 8, 7, 6, 6, 6, 5, 1, 1]` = **3**\n\
+            `4d6K3`: `[5, 4, 1, 1]` = **10**, Reason: `stats`\n\
+            `coc(50)`: `[00] + [3]` = **3** / 50 → **Extreme success**\n",
+            roll(&["10d10 t8", "4d6K3 : stats", "coc(50)"], 3)
+        );
+    }
+
+    #[test]
+    fn multi_segment_roll_is_rerolled_and_replayed() {
+        let segments = ["2d6 + 3 : damage", "3d8 + 1d4", "4d6K3"];
+        let reply = roll_segments(&segments, None, &mut rng_from_seed(6));
+        assert_eq!(
+            "`2d6 + 3`: `[6, 5] + 3` = **14**, Reason: `damage`\n\
+            `3d8 + 1d4`: `[6, 5, 5] + [4]` = **20**\n\
+            `4d6K3`: `[4, 4, 2, 1]` = **10**\n",
+            reply.results()
+        );
+        let fair = FairRoll {
+            nonce: 0,
+            input: reply.input.clone(),
+        };
+        assert_eq!(
+            reply.results(),
+            replay_roll(
+                "/roll 2d6 + 3 : damage ; 3d8 + 1d4 ; 4d6K3",
+                &fair,
+                &mut rng_from_seed(6)
+            )
+        );
+
+        // every segment is kept for `/reroll`, the last one first
+        let mut rolls = VecDeque::new();
+        for segment in reply.segments.iter().flatten() {
+            let last_roll = LastRoll::new(&segment.roller, &segment.roll, segment.system());
+            push_last_roll(&mut rolls, last_roll);
+        }
+        let expressions: Vec<&str> = rolls.iter().map(LastRoll::expression).collect();
+        assert_eq!(vec!["4d6K3", "3d8 + 1d4", "2d6 + 3"], expressions);
+
+        // `/rr 2` rolls the second segment again
+        let roller = &rolls[1].roller;
+        let res = roll_once(roller, &mut rng_from_seed(7))
+            .unwrap()
+            .to_string();
+        assert_eq!(
+            "reroll `3d8 + 1d4`: `[5, 3, 1] + [4]` = **13**",
+            format_reroll(roller.as_str(), &res)
+        );
+        let fair = FairRoll {
+            nonce: 1,
+            input: roller.as_str().to_string(),
+        };
+        assert_eq!(
+            res,
+            replay_roll("/reroll 3d8 + 1d4", &fair, &mut rng_from_seed(7))
+        );
+
+        // `/reroll_dice` rolls the first dice of the last segment
+        let dice = first_dice(&rolls[0].roller).unwrap();
+        let res = roll_once(&caith::Roller::new(&dice).unwrap(), &mut rng_from_seed(8));
+        let res = res.unwrap().to_string();
+        assert_eq!(
+            "reroll `4d6K3`: `[6, 5, 3, 3]` = **14**",
+            format_reroll(&dice, &res)
+        );
+        let fair = FairRoll {
+            nonce: 2,
+            input: dice.clone(),
+        };
+        assert_eq!(
+            res,
+            replay_roll("/reroll_dice", &fair, &mut rng_from_seed(8))
+        );
+    }
+
+    #[test]
+    fn roll_game_systems() {
+        let reply = roll_segments(
+            &["ova(3) : attack", "cde(2, fire)", "unknown"],
+            None,
+            &mut rng_from_seed(1),
+        );
+        assert!(reply.segments[0].is_ok());
+        assert!(reply.segments[1].is_ok());
+        match &reply.segments[2] {
+            Err(err) => assert!(err.ends_with(", or an alias\n```"), "{}", err),
+            Ok(_) => panic!("`unknown` should not roll"),
+        }
+    }
+
+    #[test]
+    fn reroll_is_reproducible() {
+        let roller = caith::Roller::new("2d6 + 3").unwrap();
+        let res = roll_once(&roller, &mut rng_from_seed(4)).unwrap();
+        assert_eq!(
+            "reroll `2d6 + 3`: `[4, 4] + 3` = **11**",
+            format_reroll(roller.as_str(), &res.to_string())
+        );
+    }
+
+    #[test]
+    fn reroll_of_system_call_is_replayed() {
+        let (segment, _) = roll_segment("coc(60, -1)", &mut rng_from_seed(2)).unwrap();
+        let fair = FairRoll {
+            nonce: 0,
+            input: "coc(60, -1)".to_string(),
+        };
+        assert_eq!(
+            segment.text,
+            replay_roll("/reroll coc(60, -1)", &fair, &mut rng_from_seed(2))
+        );
+    }
+
+    #[test]
+    fn reroll_dice_is_reproducible() {
+        let roller = caith::Roller::new("3d8 + 1d4 + 2").unwrap();
+        let dice = first_dice(&roller).unwrap();
+        assert_eq!("3d8", dice);
+        let res = roll_once(&caith::Roller::new(&dice).unwrap(), &mut rng_from_seed(5)).unwrap();
+        assert_eq!(
+            "reroll `3d8`: `[8, 7, 7]` = **22**",
+            format_reroll(&dice, &res.to_string())
+        );
+    }
+
+    #[test]
+    fn reroll_history_keeps_distinct_expressions() {
+        let last_roll = |expr: &str| LastRoll {
+            roller: caith::Roller::new(expr).unwrap(),
+            history: None,
+            result: String::new(),
+            system: None,
+        };
+        let expressions = |rolls: &VecDeque<LastRoll>| -> Vec<String> {
+            rolls
+                .iter()
+                .map(|r| r.roller.as_str().to_string())
+                .collect()
+        };
+        let mut rolls = VecDeque::new();
+        push_last_roll(&mut rolls, last_roll("1d20 + 5"));
+        push_last_roll(&mut rolls, last_roll("2d6 + 3"));
+        assert_eq!(vec!["2d6 + 3", "1d20 + 5"], expressions(&rolls));
+
+        push_last_roll(&mut rolls, last_roll("1d20 + 5"));
+        assert_eq!(vec!["1d20 + 5", "2d6 + 3"], expressions(&rolls));
+
+        // the reason is not part of the expression
+        let mut roller = caith::Roller::new("2d6 + 3 : damage").unwrap();
+        roller.trim_reason();
+        push_last_roll(
+            &mut rolls,
+            LastRoll {
+                roller,
+                ..last_roll("2d6 + 3")
+            },
+        );
+        assert_eq!(2, rolls.len());
+        assert_eq!("2d6 + 3", rolls[0].expression());
+
+        for i in 1..=MAX_REROLL_HISTORY {
+            push_last_roll(&mut rolls, last_roll(&format!("{}d8", i)));
+        }
+        assert_eq!(MAX_REROLL_HISTORY, rolls.len());
+        assert_eq!("5d8", rolls[0].roller.as_str());
+        assert_eq!("1d8", rolls[MAX_REROLL_HISTORY - 1].roller.as_str());
+    }
+
+    #[test]
+    fn parse_dice_selection() {
+        assert_eq!(Ok(DiceSelection::Index(3)), "3".parse());
+        assert_eq!(Ok(DiceSelection::Value(1)), "1s".parse());
+        assert_eq!(Ok(DiceSelection::Lowest), "Lowest".parse());
+        assert!("0".parse::<DiceSelection>().is_err());
+        assert!("highest".parse::<DiceSelection>().is_err());
+    }
+
+    #[test]
+    fn only_plain_dices_are_rerolled() {
+        assert_eq!(Ok(vec![6, 20]), check_plain_dices("2d6 + 1D20 * 2"));
+        assert!(check_plain_dices("4d6K3").is_err());
+        assert!(check_plain_dices("6d10 t8").is_err());
+        assert!(check_plain_dices("4d6 d1 + 1d8").is_err());
+        assert!(check_plain_dices("4dF").is_err());
+    }
+
+    #[test]
+    fn reroll_selected_dices_is_reproducible() {
+        let roller = caith::Roller::new("3d6 + 1d4 + 2").unwrap();
+        let res = roll_once(&roller, &mut rng_from_seed(9)).unwrap();
+        assert_eq!("`[3, 1, 1] + [3] + 2` = **10**", res.to_string());
+        let mut history = res.as_single().unwrap().get_history().clone();
+        let sides = check_plain_dices(roller.as_str()).unwrap();
+
+        let selected = select_dices(&history, &DiceSelection::Value(1)).unwrap();
+        assert_eq!(vec![1, 2], selected);
+        let input = reroll_expression(&history, &sides, &selected).unwrap();
+        assert_eq!("(3 + 1d6 + 1d6) + 3 + 2", input);
+        let rerolled = caith::Roller::new(&input).unwrap();
+        let rerolled = roll_once(&rerolled, &mut rng_from_seed(8)).unwrap();
+        assert_eq!(
+            "reroll the 1s of `3d6 + 1d4 + 2`: `(3 + [3] + [5]) + 3 + 2` = **16**",
+            format_selected_reroll(
+                &DiceSelection::Value(1),
+                roller.as_str(),
+                &rerolled.to_string()
+            )
+        );
+
+        update_history(&mut history, &sides, &selected, &rerolled);
+        let selected = select_dices(&history, &DiceSelection::Lowest).unwrap();
+        assert_eq!(vec![0], selected);
+        assert_eq!(
+            "(1d6 + 3 + 5) + 3 + 2",
+            reroll_expression(&history, &sides, &selected).unwrap()
+        );
+        assert_eq!(
+            "(3 + 3 + 5) + 1d4 + 2",
+            reroll_expression(&history, &sides, &[3]).unwrap()
+        );
+        assert!(select_dices(&history, &DiceSelection::Index(5)).is_err());
+    }
+
+    #[test]
+    fn parse_dice_terms_and_targets() {
+        assert_eq!(
+            vec![
+                DiceTerm {
+                    sides: 6,
+                    options: " t6".to_string()
+                },
+                DiceTerm {
+                    sides: 10,
+                    options: "t[5, 6]".to_string()
+                }
+            ],
+            dice_terms("5d6 t6 + 2 + 3d10t[5, 6]")
+        );
+        assert_eq!(None, Target::parse(" e6 K3"));
+        let target = Target::parse(" t8 tt10 f1").unwrap();
+        assert_eq!(
+            (0, 1, 2),
+            (
+                target.successes(7),
+                target.successes(8),
+                target.successes(10)
+            )
+        );
+        let target = Target::parse("t[1, 3]").unwrap();
+        assert_eq!((1, 0), (target.successes(3), target.successes(2)));
+    }
+
+    #[test]
+    fn push_is_reproducible() {
+        let roller = caith::Roller::new("5d6 t6 + 3d8 t7").unwrap();
+        let res = roll_once(&roller, &mut rng_from_seed(10)).unwrap();
+        assert_eq!("`[6, 4, 2, 2, 1] + [4, 2, 2]` = **1**", res.to_string());
+        let history = res.as_single().unwrap().get_history().clone();
+        let (input, selected) = push_expression(&history, &dice_terms(roller.as_str())).unwrap();
+        assert_eq!("(1 + 4d6 t6) + 3d8 t7", input);
+        assert_eq!(vec![1, 2, 3, 4, 5, 6, 7], selected);
+        let pushed = roll_once(&caith::Roller::new(&input).unwrap(), &mut rng_from_seed(11));
+        assert_eq!(
+            "push `5d6 t6 + 3d8 t7`: `[6, 4, 2, 2, 1] + [4, 2, 2]` = **1** → \
+            `(1 + [6, 4, 3, 2]) + [8, 6, 5]` = **3**\n1 willpower spent, 2 left",
+            format_push(
+                roller.as_str(),
+                &res.to_string(),
+                &pushed.unwrap().to_string(),
+                Some(("willpower", 2))
+            )
+        );
+
+        let roller = caith::Roller::new("2d6").unwrap();
+        let res = roll_once(&roller, &mut rng_from_seed(10)).unwrap();
+        let history = res.as_single().unwrap().get_history().clone();
+        assert!(push_expression(&history, &dice_terms(roller.as_str())).is_err());
+
+        // the explosions are groups of their own, the dices can't be matched with their term
+        let roller = caith::Roller::new("5d6 ie6 t6").unwrap();
+        let res = roll_once(&roller, &mut rng_from_seed(1)).unwrap();
+        assert_eq!("`[6, 4, 4, 3, 3][3][3]` = **1**", res.to_string());
+        let history = res.as_single().unwrap().get_history().clone();
+        assert_eq!(
+            Err("Rolls with exploding or rerolled dices can't be pushed".to_string()),
+            push_expression(&history, &dice_terms(roller.as_str()))
+        );
+    }
+
+    #[test]
+    fn replay_fair_roll() {
+        let input = "1d20 + 5 : attack ; (2d6) ^ 3";
+        let segments: Vec<&str> = split_segments(input).collect();
+        let rolled = roll_segments(&segments, None, &mut rng_from_seed(6)).results();
+        let fair = FairRoll {
+            nonce: 1,
+            input: input.to_string(),
+        };
+        assert_eq!(rolled, replay_roll(input, &fair, &mut rng_from_seed(6)));
+
+        let fair = FairRoll {
+            nonce: 2,
+            input: "1d6".to_string(),
+        };
+        let res = roll_once(&caith::Roller::new("1d6").unwrap(), &mut rng_from_seed(7)).unwrap();
+        assert_eq!(
+            res.to_string(),
+            replay_roll("/reroll 1d6", &fair, &mut rng_from_seed(7))
+        );
+    }
+}
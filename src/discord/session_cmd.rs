@@ -0,0 +1,187 @@
+use serenity::{
+    client::Context,
+    framework::standard::{
+        macros::{command, group},
+        Args, CommandResult,
+    },
+    model::{
+        channel::{Channel, Message},
+        id::UserId,
+    },
+};
+
+use crate::{
+    fair::{roll_rng, sha256_hex},
+    history::{now, LogEntry},
+    session::{format_date, EventKind, ExportFormat},
+};
+
+use super::{
+    alias_cmd::{chat_id, get_user_name, Aliases},
+    roll_cmd::replay_roll,
+    send_message,
+};
+
+#[group]
+#[commands(session, verify)]
+struct Session;
+
+/// Add the event to the session running in the channel, if any
+pub(crate) async fn record_event(
+    ctx: &Context,
+    msg: &Message,
+    kind: EventKind,
+    expression: &str,
+    result: &str,
+) {
+    let user_name = get_user_name(ctx, msg).await;
+    let entry = LogEntry::new(*msg.author.id.as_u64(), &user_name, expression, result);
+    let mut data = ctx.data.write().await;
+    let all_data = data.get_mut::<Aliases>().unwrap();
+    all_data.record_event(chat_id(msg), *msg.channel_id.as_u64(), kind, entry);
+}
+
+async fn channel_name(ctx: &Context, msg: &Message) -> String {
+    match msg.channel(&ctx.cache).await {
+        Some(Channel::Guild(channel)) => channel.name,
+        Some(_) | None => "private".to_string(),
+    }
+}
+
+async fn export(ctx: &Context, msg: &Message, format: ExportFormat) -> CommandResult {
+    let name = channel_name(ctx, msg).await;
+    let transcript = {
+        let data = ctx.data.read().await;
+        let all_data = data.get::<Aliases>().unwrap();
+        all_data
+            .session(chat_id(msg), *msg.channel_id.as_u64())
+            .map(|session| {
+                (
+                    session.export(format, &name),
+                    format_date(session.started_at),
+                )
+            })
+    };
+    match transcript {
+        Some((transcript, date)) => {
+            let filename = format!("session-{}-{}.{}", name, date, format.extension());
+            msg.channel_id
+                .send_files(
+                    &ctx.http,
+                    vec![(transcript.as_bytes(), filename.as_str())],
+                    |m| m.reference_message(msg).content("Session transcript"),
+                )
+                .await?;
+        }
+        None => {
+            send_message(ctx, msg, "No session recorded in this channel").await?;
+        }
+    }
+    Ok(())
+}
+
+#[command]
+/// ```
+/// /session start [fair]|end|export md|html
+///
+/// Record the rolls, card draws and reveals of the channel between `start` and `end`.
+/// `export` uploads the transcript of the running or last session, as Markdown or HTML.
+///
+/// With `fair`, the rolls are provably fair: a commitment of a secret seed is given at the
+/// start, each roll is derived from the seed, the user and a counter (nonce), and the seed is
+/// revealed at the end so anyone can check the rolls with `/verify`.
+///
+/// Ex: `/session start fair`
+/// ```
+async fn session(ctx: &Context, msg: &Message, mut args: Args) -> CommandResult {
+    let cmd = args.single::<String>().unwrap_or_default().to_lowercase();
+    let msg_to_send = match cmd.as_str() {
+        "start" | "end" => {
+            let mut data = ctx.data.write().await;
+            let all_data = data.get_mut::<Aliases>().unwrap();
+            if cmd == "start" {
+                let fair = args.rest().trim().eq_ignore_ascii_case("fair");
+                all_data.start_session(chat_id(msg), *msg.channel_id.as_u64(), now(), fair)
+            } else {
+                all_data.end_session(chat_id(msg), *msg.channel_id.as_u64(), now())
+            }
+        }
+        "export" => match args.rest().parse::<ExportFormat>() {
+            Ok(format) => return export(ctx, msg, format).await,
+            Err(err) => err,
+        },
+        _ => "Bad parameter: `/session start [fair]|end|export md|html`".to_string(),
+    };
+    send_message(ctx, msg, &msg_to_send).await?;
+    Ok(())
+}
+
+#[command]
+/// ```
+/// /verify [user] <nonce>
+///
+/// Once a provably fair session is over, roll again the roll with the given nonce of the
+/// mentioned user, or yours, and check that it gives the logged result.
+///
+/// Ex: `/verify @Geob 3`
+/// ```
+async fn verify(ctx: &Context, msg: &Message, mut args: Args) -> CommandResult {
+    let mut user_id = msg.author.id;
+    let mut nonce = None;
+    let mut bad_param = false;
+    while !args.is_empty() {
+        let arg = args.single::<String>().unwrap();
+        if arg.starts_with("<@") {
+            match arg.parse::<UserId>() {
+                Ok(id) => user_id = id,
+                Err(_) => bad_param = true,
+            }
+        } else {
+            match arg.parse::<u64>() {
+                Ok(n) => nonce = Some(n),
+                Err(_) => bad_param = true,
+            }
+        }
+    }
+
+    let msg_to_send = match nonce {
+        Some(nonce) if !bad_param => {
+            let data = ctx.data.read().await;
+            let all_data = data.get::<Aliases>().unwrap();
+            match all_data.fair_roll_entry(
+                chat_id(msg),
+                *msg.channel_id.as_u64(),
+                *user_id.as_u64(),
+                nonce,
+            ) {
+                Ok((seed, entry, fair)) => {
+                    let mut rng = roll_rng(&seed.seed, *user_id.as_u64(), nonce);
+                    let replayed = replay_roll(&entry.expression, fair, &mut rng);
+                    let commitment = if sha256_hex(&seed.seed) == seed.commitment {
+                        "✅ the seed matches its commitment"
+                    } else {
+                        "❌ the seed doesn't match its commitment"
+                    };
+                    let result = if replayed == entry.result {
+                        "✅ same result as logged".to_string()
+                    } else {
+                        format!("❌ different result, got: {}", replayed)
+                    };
+                    format!(
+                        "Roll {} of {}: `{}` → {}\n{}\n{}",
+                        nonce,
+                        entry.user_name,
+                        fair.input,
+                        entry.result.trim_end(),
+                        commitment,
+                        result
+                    )
+                }
+                Err(err) => err,
+            }
+        }
+        _ => "Bad parameter: `/verify [user] <nonce>`".to_string(),
+    };
+    send_message(ctx, msg, &msg_to_send).await?;
+    Ok(())
+}
@@ -0,0 +1,65 @@
+use serenity::{
+    client::Context,
+    framework::standard::{
+        macros::{command, group},
+        Args, CommandResult,
+    },
+    model::{channel::Message, id::UserId},
+};
+
+use super::{
+    alias_cmd::{chat_id, get_user_name, Aliases},
+    send_message,
+};
+
+#[group]
+#[commands(stats)]
+struct Stats;
+
+#[command]
+/// ```
+/// /stats [user | on | off]
+///
+/// Show the dice statistics of the mentioned user, or yours: number of rolls, critics, and
+/// for each dice size the average compared to the expected one and the count of each face.
+///
+/// `/stats off` stops counting your rolls and deletes your stats, `/stats on` counts them again.
+///
+/// Ex: `/stats @Geob`
+/// ```
+async fn stats(ctx: &Context, msg: &Message, args: Args) -> CommandResult {
+    let arg = args.rest().trim();
+    let msg_to_send = match arg {
+        "on" | "off" => {
+            let mut data = ctx.data.write().await;
+            let all_data = data.get_mut::<Aliases>().unwrap();
+            all_data.set_stats_opt_out(chat_id(msg), *msg.author.id.as_u64(), arg == "off")
+        }
+        _ => {
+            let user = if arg.is_empty() {
+                Ok((msg.author.id, get_user_name(ctx, msg).await))
+            } else {
+                match arg.parse::<UserId>() {
+                    Ok(id) => match msg.mentions.iter().find(|u| u.id == id) {
+                        Some(user) => Ok((id, user.name.clone())),
+                        None => Ok((id, arg.to_string())),
+                    },
+                    Err(_) => Err("Bad parameter: `/stats [user | on | off]`".to_string()),
+                }
+            };
+            match user {
+                Ok((user_id, user_name)) => {
+                    let data = ctx.data.read().await;
+                    let all_data = data.get::<Aliases>().unwrap();
+                    match all_data.user_stats(chat_id(msg), *user_id.as_u64()) {
+                        Some(stats) => format!("Stats of {}: {}", user_name, stats.report()),
+                        None => format!("No stats for {}", user_name),
+                    }
+                }
+                Err(err) => err,
+            }
+        }
+    };
+    send_message(ctx, msg, &msg_to_send).await?;
+    Ok(())
+}
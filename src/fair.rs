@@ -0,0 +1,130 @@
+use std::{collections::HashMap, fmt::Write};
+
+use rand::{rngs::OsRng, RngCore};
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+
+use crate::rng::{seeded_rng, DiceRng};
+
+/// Server seed of a provably fair session.
+///
+/// The commitment (SHA-256 of the seed) is published when the session starts and the seed is
+/// revealed when it ends, so players can check that each roll was derived from it.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct FairSeed {
+    // hexadecimal
+    pub seed: String,
+    // hexadecimal SHA-256 of `seed`
+    pub commitment: String,
+    // user id, number of fair rolls done by the user
+    nonces: HashMap<u64, u64>,
+}
+
+/// What is needed to recompute a fair roll
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct FairRoll {
+    pub nonce: u64,
+    // expression given to the RNG, after alias expansion
+    pub input: String,
+}
+
+fn to_hex(bytes: &[u8]) -> String {
+    bytes.iter().fold(String::new(), |mut s, b| {
+        let _ = write!(s, "{:02x}", b);
+        s
+    })
+}
+
+pub fn sha256_hex(data: &str) -> String {
+    to_hex(&Sha256::digest(data.as_bytes()))
+}
+
+impl FairSeed {
+    pub fn generate() -> Self {
+        let mut seed = [0u8; 32];
+        OsRng.fill_bytes(&mut seed);
+        FairSeed::from_seed(to_hex(&seed))
+    }
+
+    fn from_seed(seed: String) -> Self {
+        FairSeed {
+            commitment: sha256_hex(&seed),
+            seed,
+            nonces: HashMap::new(),
+        }
+    }
+
+    /// RNG for the next roll of the user, with the nonce to give to `roll_rng` to replay it
+    pub fn next_roll(&mut self, user_id: u64) -> (DiceRng, u64) {
+        let nonce = self.nonces.entry(user_id).or_insert(0);
+        *nonce += 1;
+        (roll_rng(&self.seed, user_id, *nonce), *nonce)
+    }
+}
+
+/// Commitment of a hidden text: a random salt and `SHA-256(salt:text)`. Revealing the salt
+/// later proves the text was not altered.
+pub fn commit(text: &str) -> (String, String) {
+    let mut salt = [0u8; 16];
+    OsRng.fill_bytes(&mut salt);
+    let salt = to_hex(&salt);
+    let commitment = sha256_hex(&format!("{}:{}", salt, text));
+    (salt, commitment)
+}
+
+pub fn check_commitment(text: &str, salt: &str, commitment: &str) -> bool {
+    sha256_hex(&format!("{}:{}", salt, text)) == commitment
+}
+
+/// RNG of a fair roll: seeded with `SHA-256(seed:user_id:nonce)`
+pub fn roll_rng(seed: &str, user_id: u64, nonce: u64) -> DiceRng {
+    let mut bytes = [0u8; 32];
+    bytes.copy_from_slice(&Sha256::digest(
+        format!("{}:{}:{}", seed, user_id, nonce).as_bytes(),
+    ));
+    seeded_rng(bytes)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn commitment_is_sha256_of_seed() {
+        assert_eq!(
+            "ba7816bf8f01cfea414140de5dae2223b00361a396177a9cb410ff61f20015ad",
+            sha256_hex("abc")
+        );
+        let seed = FairSeed::generate();
+        assert_eq!(64, seed.seed.len());
+        assert_eq!(sha256_hex(&seed.seed), seed.commitment);
+    }
+
+    #[test]
+    fn commit_hidden_text() {
+        let (salt, commitment) = commit("`[12] + 3` = **15**");
+        assert_eq!(32, salt.len());
+        assert!(check_commitment("`[12] + 3` = **15**", &salt, &commitment));
+        assert!(!check_commitment("`[19] + 3` = **22**", &salt, &commitment));
+        let (other_salt, other_commitment) = commit("`[12] + 3` = **15**");
+        assert_ne!(salt, other_salt);
+        assert_ne!(commitment, other_commitment);
+    }
+
+    #[test]
+    fn fair_rolls_can_be_replayed() {
+        let mut seed = FairSeed::from_seed("1234".to_string());
+        let (mut rng1, nonce1) = seed.next_roll(1);
+        let (mut rng2, nonce2) = seed.next_roll(1);
+        let (_, other_nonce) = seed.next_roll(2);
+        assert_eq!((1, 2, 1), (nonce1, nonce2, other_nonce));
+
+        let roller = caith::Roller::new("10d20").unwrap();
+        let res1 = roller.roll_with(&mut rng1).unwrap().to_string();
+        let res2 = roller.roll_with(&mut rng2).unwrap().to_string();
+        assert_ne!(res1, res2);
+
+        let mut replay = roll_rng("1234", 1, 2);
+        assert_eq!(res2, roller.roll_with(&mut replay).unwrap().to_string());
+    }
+}
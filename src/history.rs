@@ -0,0 +1,144 @@
+use std::{
+    collections::VecDeque,
+    time::{SystemTime, UNIX_EPOCH},
+};
+
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    alias::{AllData, Data},
+    fair::FairRoll,
+    session::EventKind,
+};
+
+/// Maximum number of rolls kept per channel
+pub const MAX_LOG_ENTRIES: usize = 200;
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct LogEntry {
+    pub user_id: u64,
+    pub user_name: String,
+    // the command as typed by the user
+    pub expression: String,
+    pub expansion: Option<String>,
+    pub result: String,
+    #[serde(default)]
+    pub crit_min: bool,
+    #[serde(default)]
+    pub crit_max: bool,
+    // seconds since UNIX epoch
+    pub timestamp: u64,
+    // only for rolls of a provably fair session
+    #[serde(default)]
+    pub fair: Option<FairRoll>,
+}
+
+impl LogEntry {
+    pub fn new(user_id: u64, user_name: &str, expression: &str, result: &str) -> Self {
+        LogEntry {
+            user_id,
+            user_name: user_name.to_string(),
+            expression: expression.to_string(),
+            expansion: None,
+            result: result.to_string(),
+            crit_min: false,
+            crit_max: false,
+            timestamp: now(),
+            fair: None,
+        }
+    }
+}
+
+/// Seconds since UNIX epoch
+pub fn now() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_secs())
+        .unwrap_or(0)
+}
+
+/// Last rolls of a channel, oldest first
+#[derive(Serialize, Deserialize, Debug, Default)]
+pub struct RollLog(VecDeque<LogEntry>);
+
+impl RollLog {
+    pub fn push(&mut self, entry: LogEntry) {
+        if self.0.len() >= MAX_LOG_ENTRIES {
+            self.0.pop_front();
+        }
+        self.0.push_back(entry);
+    }
+
+    /// The `nb` last rolls, of the given user if any, oldest first
+    pub fn last(&self, user_id: Option<u64>, nb: usize) -> Vec<&LogEntry> {
+        let mut entries: Vec<&LogEntry> = self
+            .0
+            .iter()
+            .rev()
+            .filter(|e| user_id.map_or(true, |id| e.user_id == id))
+            .take(nb)
+            .collect();
+        entries.reverse();
+        entries
+    }
+}
+
+impl AllData {
+    pub fn log_roll(&mut self, chat_id: u64, channel_id: u64, entry: LogEntry) {
+        self.record_event(chat_id, channel_id, EventKind::Roll, entry.clone());
+        let data = self.entry(chat_id).or_insert_with(Data::new);
+        data.roll_logs.entry(channel_id).or_default().push(entry);
+    }
+
+    pub fn roll_history(
+        &self,
+        chat_id: u64,
+        channel_id: u64,
+        user_id: Option<u64>,
+        nb: usize,
+    ) -> Vec<&LogEntry> {
+        match self
+            .get(&chat_id)
+            .and_then(|data| data.roll_logs.get(&channel_id))
+        {
+            Some(log) => log.last(user_id, nb),
+            None => Vec::new(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn entry(user_id: u64, result: &str) -> LogEntry {
+        LogEntry::new(user_id, "toto", "1d6", result)
+    }
+
+    #[test]
+    fn log_is_bounded() {
+        let mut log = RollLog::default();
+        for i in 0..MAX_LOG_ENTRIES + 5 {
+            log.push(entry(1, &i.to_string()));
+        }
+        let all = log.last(None, MAX_LOG_ENTRIES * 2);
+        assert_eq!(MAX_LOG_ENTRIES, all.len());
+        assert_eq!("5", all[0].result);
+    }
+
+    #[test]
+    fn last_rolls_of_user() {
+        let mut all = AllData::new();
+        all.log_roll(0, 10, entry(1, "a"));
+        all.log_roll(0, 10, entry(2, "b"));
+        all.log_roll(0, 10, entry(1, "c"));
+        all.log_roll(0, 11, entry(1, "d"));
+
+        let results =
+            |entries: Vec<&LogEntry>| entries.iter().map(|e| e.result.clone()).collect::<Vec<_>>();
+        assert_eq!(vec!["b", "c"], results(all.roll_history(0, 10, None, 2)));
+        assert_eq!(vec!["a", "c"], results(all.roll_history(0, 10, Some(1), 5)));
+        assert_eq!(vec!["d"], results(all.roll_history(0, 11, Some(1), 5)));
+        assert!(all.roll_history(0, 12, None, 5).is_empty());
+    }
+}
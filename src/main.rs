@@ -3,8 +3,19 @@ use std::collections::HashSet;
 use caith::{Critic, RollHistory, RollResult, RollResultType, SingleRollResult};
 
 mod alias;
+mod clocks;
 #[cfg(feature = "discord")]
 mod discord;
+mod fair;
+mod history;
+mod odds;
+mod progress;
+mod resources;
+mod rng;
+mod session;
+mod settings;
+mod stats;
+mod systems;
 
 #[tokio::main]
 async fn main() {
@@ -76,9 +87,3 @@ pub fn process_crit(set: Result<HashSet<Critic>, serenity::Error>) -> Option<Has
         Some(h)
     }
 }
-
-pub enum Interpreter<'a> {
-    None,
-    Ova(i32),
-    Cde(&'a str),
-}
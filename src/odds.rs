@@ -0,0 +1,416 @@
+use std::{collections::BTreeMap, fmt::Write};
+
+use caith::{RollResultType, Roller};
+use rand::Rng;
+
+use crate::rng::DiceRng;
+
+/// Number of rolls done when the distribution can't be computed exactly
+pub const MONTE_CARLO_ROLLS: u32 = 10_000;
+
+/// Maximum number of operations to compute a distribution exactly, Monte-Carlo is used above
+const MAX_EXACT_WORK: usize = 1_000_000;
+
+const HISTOGRAM_WIDTH: usize = 30;
+const HISTOGRAM_MAX_ROWS: usize = 20;
+
+/// Probability distribution of the total of an expression
+#[derive(Debug, Clone, PartialEq)]
+pub struct Distribution {
+    // total, probability
+    probs: BTreeMap<i64, f64>,
+    // `None` if the distribution is exact
+    samples: Option<u32>,
+}
+
+impl Distribution {
+    fn constant(value: i64) -> Self {
+        let mut probs = BTreeMap::new();
+        probs.insert(value, 1.0);
+        Distribution {
+            probs,
+            samples: None,
+        }
+    }
+
+    fn from_counts(counts: BTreeMap<i64, u32>, samples: u32) -> Self {
+        Distribution {
+            probs: counts
+                .into_iter()
+                .map(|(total, count)| (total, count as f64 / samples as f64))
+                .collect(),
+            samples: Some(samples),
+        }
+    }
+
+    /// Compute the distribution of the expression, exactly if it is a plain sum of dices and
+    /// constants cheap enough to compute, with a Monte-Carlo simulation using `rng` otherwise.
+    pub fn compute(expr: &str, rng: &mut DiceRng) -> Result<Self, String> {
+        let expr = match expr.find(':') {
+            Some(idx) => &expr[..idx],
+            None => expr,
+        };
+        match Distribution::exact(expr) {
+            Some(dist) => Ok(dist),
+            None => Distribution::sample(expr, MONTE_CARLO_ROLLS, rng),
+        }
+    }
+
+    /// Roll the expression `nb` times and gather the totals
+    pub fn sample(expr: &str, nb: u32, rng: &mut DiceRng) -> Result<Self, String> {
+        let roller = Roller::new(expr).map_err(|e| e.to_string())?;
+        let mut counts = BTreeMap::new();
+        for _ in 0..nb {
+            let res = roller.roll_with(rng).map_err(|e| e.to_string())?;
+            let total = match res.get_result() {
+                RollResultType::Single(res) => res.get_total(),
+                RollResultType::Repeated(res) => res.get_total().ok_or_else(|| {
+                    "Can't compute odds of a repeated roll, use `^+` to sum it".to_string()
+                })?,
+            };
+            *counts.entry(total).or_insert(0) += 1;
+        }
+        Ok(Distribution::from_counts(counts, nb))
+    }
+
+    // Only supports sum and subtraction of `NdS`, `NdS tT` and integers
+    fn exact(expr: &str) -> Option<Self> {
+        let expr: String = expr.chars().filter(|c| !c.is_whitespace()).collect();
+        if expr.is_empty() {
+            return None;
+        }
+        let mut dist = Distribution::constant(0);
+        let mut rest = expr.as_str();
+        let mut negative = false;
+        loop {
+            let end = rest.find(['+', '-']).unwrap_or(rest.len());
+            let term = parse_term(&rest[..end])?;
+            if dist.probs.len() * term.probs.len() > MAX_EXACT_WORK {
+                return None;
+            }
+            dist = dist.combine(&term, |a, b| if negative { a - b } else { a + b });
+            if end == rest.len() {
+                break;
+            }
+            negative = rest[end..].starts_with('-');
+            rest = &rest[end + 1..];
+        }
+        Some(dist)
+    }
+
+    // distribution of `op(self, other)` for independent variables
+    pub fn combine<F>(&self, other: &Distribution, op: F) -> Distribution
+    where
+        F: Fn(i64, i64) -> i64,
+    {
+        let mut probs = BTreeMap::new();
+        for (a, pa) in self.probs.iter() {
+            for (b, pb) in other.probs.iter() {
+                *probs.entry(op(*a, *b)).or_insert(0.0) += pa * pb;
+            }
+        }
+        Distribution {
+            probs,
+            samples: self.samples.max(other.samples),
+        }
+    }
+
+    // removes impossible totals that a success target can produce
+    fn without_zeroes(mut self) -> Self {
+        self.probs.retain(|_, p| *p > 0.0);
+        self
+    }
+
+    /// Number of rolls used to build the distribution, `None` if exact
+    pub fn samples(&self) -> Option<u32> {
+        self.samples
+    }
+
+    pub fn mean(&self) -> f64 {
+        self.probs.iter().map(|(v, p)| *v as f64 * p).sum()
+    }
+
+    pub fn std_dev(&self) -> f64 {
+        let mean = self.mean();
+        self.probs
+            .iter()
+            .map(|(v, p)| (*v as f64 - mean).powi(2) * p)
+            .sum::<f64>()
+            .sqrt()
+    }
+
+    /// Smallest total reached with at least the given probability (between 0 and 1)
+    pub fn percentile(&self, p: f64) -> i64 {
+        let mut acc = 0.0;
+        for (v, prob) in self.probs.iter() {
+            acc += prob;
+            // tolerate floating point error on the sum
+            if acc >= p - 1e-9 {
+                return *v;
+            }
+        }
+        *self.probs.keys().last().unwrap_or(&0)
+    }
+
+    /// Distribution of `self - other`, both being independent. Sampled if too long to compute.
+    pub fn difference(&self, other: &Distribution, rng: &mut DiceRng) -> Distribution {
+        if self.probs.len() * other.probs.len() <= MAX_EXACT_WORK {
+            return self.combine(other, |a, b| a - b);
+        }
+        let (cumulative_a, cumulative_b) = (self.cumulative(), other.cumulative());
+        let mut counts = BTreeMap::new();
+        for _ in 0..MONTE_CARLO_ROLLS {
+            let a = value_at(&cumulative_a, rng.gen());
+            let b = value_at(&cumulative_b, rng.gen());
+            *counts.entry(a - b).or_insert(0) += 1;
+        }
+        Distribution::from_counts(counts, MONTE_CARLO_ROLLS)
+    }
+
+    // totals with their cumulative probability
+    fn cumulative(&self) -> Vec<(i64, f64)> {
+        let mut acc = 0.0;
+        self.probs
+            .iter()
+            .map(|(v, p)| {
+                acc += p;
+                (*v, acc)
+            })
+            .collect()
+    }
+
+    pub fn prob_of(&self, value: i64) -> f64 {
+        *self.probs.get(&value).unwrap_or(&0.0)
+    }
+
+    pub fn prob_at_least(&self, value: i64) -> f64 {
+        self.probs.range(value..).map(|(_, p)| p).sum()
+    }
+
+    pub fn prob_at_most(&self, value: i64) -> f64 {
+        self.probs.range(..=value).map(|(_, p)| p).sum()
+    }
+
+    /// ASCII histogram, totals are grouped if there are too many of them
+    pub fn histogram(&self) -> String {
+        let (min, max) = match (self.probs.keys().next(), self.probs.keys().last()) {
+            (Some(min), Some(max)) => (*min, *max),
+            _ => return String::new(),
+        };
+        let span = (max - min + 1) as usize;
+        let bucket_size = span.div_ceil(HISTOGRAM_MAX_ROWS);
+        let buckets: Vec<(String, f64)> = (0..span.div_ceil(bucket_size))
+            .map(|i| {
+                let start = min + (i * bucket_size) as i64;
+                let end = (start + bucket_size as i64 - 1).min(max);
+                let label = if start == end {
+                    start.to_string()
+                } else {
+                    format!("{}..{}", start, end)
+                };
+                (label, self.probs.range(start..=end).map(|(_, p)| p).sum())
+            })
+            .collect();
+
+        let label_width = buckets.iter().map(|(l, _)| l.len()).max().unwrap_or(0);
+        let max_prob = buckets.iter().map(|(_, p)| *p).fold(0.0, f64::max);
+        buckets.iter().fold(String::new(), |mut s, (label, p)| {
+            let bar = (p / max_prob * HISTOGRAM_WIDTH as f64).round() as usize;
+            let _ = writeln!(
+                s,
+                "{:>width$} | {} {:.2}%",
+                label,
+                "█".repeat(bar),
+                p * 100.0,
+                width = label_width
+            );
+            s
+        })
+    }
+}
+
+// the total whose cumulative probability reaches `p`, to sample a distribution
+fn value_at(cumulative: &[(i64, f64)], p: f64) -> i64 {
+    let idx = cumulative.partition_point(|(_, acc)| *acc < p);
+    cumulative
+        .get(idx)
+        .or_else(|| cumulative.last())
+        .map_or(0, |(v, _)| *v)
+}
+
+fn parse_term(term: &str) -> Option<Distribution> {
+    if let Ok(value) = term.parse::<i64>() {
+        return Some(Distribution::constant(value));
+    }
+
+    let d = term.find(['d', 'D'])?;
+    let nb = match &term[..d] {
+        "" => 1,
+        nb => nb.parse::<u64>().ok()?,
+    };
+    let (sides, target) = match term[d + 1..].find('t') {
+        Some(t) => (
+            term[d + 1..d + 1 + t].parse::<u64>().ok()?,
+            Some(term[d + 2 + t..].parse::<u64>().ok()?),
+        ),
+        None => (term[d + 1..].parse::<u64>().ok()?, None),
+    };
+    if nb == 0 || sides == 0 || sides > 1000 || nb > 100 {
+        return None;
+    }
+    // each added dice is combined with the sum of the previous ones
+    let support = if target.is_some() { 2 } else { sides as usize };
+    if (1..nb as usize)
+        .map(|k| k * support * support)
+        .sum::<usize>()
+        > MAX_EXACT_WORK
+    {
+        return None;
+    }
+
+    let die = match target {
+        // a die counts as one success if it reaches the target
+        Some(target) => {
+            let success = (sides + 1).saturating_sub(target.max(1)).min(sides) as f64;
+            let mut probs = BTreeMap::new();
+            probs.insert(0, 1.0 - success / sides as f64);
+            probs.insert(1, success / sides as f64);
+            Distribution {
+                probs,
+                samples: None,
+            }
+        }
+        None => Distribution {
+            probs: (1..=sides as i64)
+                .map(|v| (v, 1.0 / sides as f64))
+                .collect(),
+            samples: None,
+        },
+    };
+    Some(
+        (1..nb)
+            .fold(die.clone(), |acc, _| acc.combine(&die, |a, b| a + b))
+            .without_zeroes(),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::rng::rng_from_seed;
+
+    fn compute(expr: &str) -> Result<Distribution, String> {
+        Distribution::compute(expr, &mut rng_from_seed(1))
+    }
+
+    fn assert_close(expected: f64, value: f64) {
+        assert!(
+            (expected - value).abs() < 1e-9,
+            "expected {}, got {}",
+            expected,
+            value
+        );
+    }
+
+    #[test]
+    fn exact_2d6() {
+        let dist = compute("2d6").unwrap();
+        assert_eq!(None, dist.samples());
+        assert_close(7.0, dist.mean());
+        assert_close(21.0 / 36.0, dist.prob_at_least(7));
+        assert_close(1.0 / 36.0, dist.prob_at_most(2));
+        assert_eq!(7, dist.percentile(0.5));
+        assert_eq!(2, *dist.probs.keys().next().unwrap());
+        assert_eq!(12, *dist.probs.keys().last().unwrap());
+    }
+
+    #[test]
+    fn exact_with_modifier_and_reason() {
+        let dist = compute("d20 + 5 - 1d4 : attack").unwrap();
+        assert_eq!(None, dist.samples());
+        assert_close(10.5 + 5.0 - 2.5, dist.mean());
+        assert_eq!(2, *dist.probs.keys().next().unwrap());
+        assert_eq!(24, *dist.probs.keys().last().unwrap());
+    }
+
+    #[test]
+    fn exact_success_pool() {
+        // 6d10 t8: each die has 3 chances out of 10 to be a success
+        let dist = compute("6d10 t8").unwrap();
+        assert_eq!(None, dist.samples());
+        assert_close(1.8, dist.mean());
+        let p3 = 20.0 * 0.3f64.powi(3) * 0.7f64.powi(3);
+        assert_close(p3, dist.probs[&3]);
+    }
+
+    #[test]
+    fn fallback_to_monte_carlo() {
+        let dist = compute("4d6K3").unwrap();
+        assert_eq!(Some(MONTE_CARLO_ROLLS), dist.samples());
+        assert!(dist.mean() > 11.5 && dist.mean() < 13.0);
+        assert!(*dist.probs.keys().next().unwrap() >= 3);
+        assert!(*dist.probs.keys().last().unwrap() <= 18);
+    }
+
+    #[test]
+    fn huge_expressions_are_sampled() {
+        let start = std::time::Instant::now();
+        let dist = compute("100d1000").unwrap();
+        assert_eq!(Some(MONTE_CARLO_ROLLS), dist.samples());
+        let diff = dist.difference(&dist, &mut rng_from_seed(2));
+        assert_eq!(Some(MONTE_CARLO_ROLLS), diff.samples());
+        assert_eq!(diff, dist.difference(&dist, &mut rng_from_seed(2)));
+        assert_ne!(diff, dist.difference(&dist, &mut rng_from_seed(3)));
+        assert!(start.elapsed().as_secs() < 10, "took {:?}", start.elapsed());
+        // still exact when cheap enough
+        assert_eq!(None, compute("100d6 t5").unwrap().samples());
+        assert_eq!(None, compute("10d20 + 3d100").unwrap().samples());
+    }
+
+    #[test]
+    fn sampling_is_reproducible() {
+        let sample = |seed| Distribution::compute("4d6K3", &mut rng_from_seed(seed)).unwrap();
+        assert_eq!(sample(3), sample(3));
+        assert_ne!(sample(3), sample(4));
+    }
+
+    #[test]
+    fn repeated_roll_needs_sum() {
+        assert!(compute("(2d6) ^ 3").is_err());
+        assert!(compute("(2d6) ^+ 3").is_ok());
+    }
+
+    #[test]
+    fn compare_same_expression() {
+        let d20 = compute("1d20").unwrap();
+        let diff = d20.difference(&d20, &mut rng_from_seed(2));
+        assert_close(1.0 / 20.0, diff.prob_of(0));
+        assert_close(diff.prob_at_least(1), diff.prob_at_most(-1));
+        assert_close(0.0, diff.mean());
+    }
+
+    #[test]
+    fn compare_different_expressions() {
+        // 2d6 always beats 1
+        let diff = compute("2d6")
+            .unwrap()
+            .difference(&compute("1").unwrap(), &mut rng_from_seed(2));
+        assert_close(1.0, diff.prob_at_least(1));
+        // 1d4 vs 1d4 + 1: tie when the first is one above the second
+        let diff = compute("1d4")
+            .unwrap()
+            .difference(&compute("1d4 + 1").unwrap(), &mut rng_from_seed(2));
+        assert_close(3.0 / 16.0, diff.prob_of(0));
+        assert_close(3.0 / 16.0, diff.prob_at_least(1));
+        assert_close(10.0 / 16.0, diff.prob_at_most(-1));
+    }
+
+    #[test]
+    fn histogram_groups_totals() {
+        let dist = compute("1d6").unwrap();
+        assert_eq!(6, dist.histogram().lines().count());
+        let dist = compute("1d100").unwrap();
+        assert_eq!(20, dist.histogram().lines().count());
+        assert!(dist.histogram().starts_with("   1..5 |"));
+    }
+}
@@ -0,0 +1,212 @@
+use std::{collections::BTreeMap, fmt::Display, str::FromStr};
+
+use serde::{Deserialize, Serialize};
+
+use crate::alias::{AllData, Data};
+
+// A track has 10 boxes of 4 ticks
+const BOXES: u8 = 10;
+const TICKS_PER_BOX: u8 = 4;
+
+/// Rank of an Ironsworn challenge, tells how much progress is marked at once
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq)]
+#[serde(rename_all = "lowercase")]
+pub enum Rank {
+    Troublesome,
+    Dangerous,
+    Formidable,
+    Extreme,
+    Epic,
+}
+
+impl Rank {
+    fn ticks(self) -> u8 {
+        match self {
+            Rank::Troublesome => 12,
+            Rank::Dangerous => 8,
+            Rank::Formidable => 4,
+            Rank::Extreme => 2,
+            Rank::Epic => 1,
+        }
+    }
+}
+
+impl FromStr for Rank {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim().to_lowercase().as_str() {
+            "troublesome" => Ok(Rank::Troublesome),
+            "dangerous" => Ok(Rank::Dangerous),
+            "formidable" => Ok(Rank::Formidable),
+            "extreme" => Ok(Rank::Extreme),
+            "epic" => Ok(Rank::Epic),
+            _ => Err(format!(
+                "Unknown rank `{}`, expected troublesome, dangerous, formidable, extreme or epic",
+                s
+            )),
+        }
+    }
+}
+
+impl Display for Rank {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let rank = match self {
+            Rank::Troublesome => "troublesome",
+            Rank::Dangerous => "dangerous",
+            Rank::Formidable => "formidable",
+            Rank::Extreme => "extreme",
+            Rank::Epic => "epic",
+        };
+        write!(f, "{}", rank)
+    }
+}
+
+/// Progress track of an Ironsworn vow, journey or fight
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+pub struct ProgressTrack {
+    pub rank: Rank,
+    pub ticks: u8,
+}
+
+impl ProgressTrack {
+    /// The progress score: the number of full boxes
+    pub fn score(&self) -> u8 {
+        self.ticks / TICKS_PER_BOX
+    }
+}
+
+impl Display for ProgressTrack {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let filled = "■".repeat(self.score() as usize);
+        let empty = "□".repeat((BOXES - self.score()) as usize);
+        write!(f, "[{}{}] {}/{}", filled, empty, self.score(), BOXES)?;
+        match self.ticks % TICKS_PER_BOX {
+            0 => (),
+            1 => write!(f, " +1 tick")?,
+            ticks => write!(f, " +{} ticks", ticks)?,
+        }
+        write!(f, " ({})", self.rank)
+    }
+}
+
+impl AllData {
+    /// Progress tracks of the user's character, by name
+    pub fn progress_tracks(
+        &self,
+        chat_id: u64,
+        user_id: u64,
+    ) -> Option<&BTreeMap<String, ProgressTrack>> {
+        self.get(&chat_id)
+            .and_then(|data| data.progress.get(&user_id))
+            .filter(|tracks| !tracks.is_empty())
+    }
+
+    pub fn new_track(
+        &mut self,
+        chat_id: u64,
+        user_id: u64,
+        name: &str,
+        rank: Rank,
+    ) -> Result<String, String> {
+        let data = self.entry(chat_id).or_insert_with(Data::new);
+        let tracks = data.progress.entry(user_id).or_default();
+        if tracks.contains_key(name) {
+            return Err(format!("Progress track `{}` already exists", name));
+        }
+        let track = ProgressTrack { rank, ticks: 0 };
+        let msg = format!("{}: {}", name, track);
+        tracks.insert(name.to_string(), track);
+        Ok(msg)
+    }
+
+    fn track_mut(
+        &mut self,
+        chat_id: u64,
+        user_id: u64,
+        name: &str,
+    ) -> Result<&mut ProgressTrack, String> {
+        self.get_mut(&chat_id)
+            .and_then(|data| data.progress.get_mut(&user_id))
+            .and_then(|tracks| tracks.get_mut(name))
+            .ok_or_else(|| format!("No progress track named `{}`", name))
+    }
+
+    /// Mark progress as many times as asked (a negative number clears progress)
+    pub fn mark_progress(
+        &mut self,
+        chat_id: u64,
+        user_id: u64,
+        name: &str,
+        marks: i16,
+    ) -> Result<String, String> {
+        let track = self.track_mut(chat_id, user_id, name)?;
+        // the ticks of an i16 number of marks always fit in an i32
+        let max = (BOXES * TICKS_PER_BOX) as i32;
+        let ticks = track.ticks as i32 + marks as i32 * track.rank.ticks() as i32;
+        track.ticks = ticks.clamp(0, max) as u8;
+        Ok(format!("{}: {}", name, track))
+    }
+
+    pub fn progress_score(&mut self, chat_id: u64, user_id: u64, name: &str) -> Result<u8, String> {
+        self.track_mut(chat_id, user_id, name)
+            .map(|track| track.score())
+    }
+
+    pub fn delete_track(
+        &mut self,
+        chat_id: u64,
+        user_id: u64,
+        name: &str,
+    ) -> Result<String, String> {
+        self.get_mut(&chat_id)
+            .and_then(|data| data.progress.get_mut(&user_id))
+            .and_then(|tracks| tracks.remove(name))
+            .map(|_| format!("Progress track `{}` deleted", name))
+            .ok_or_else(|| format!("No progress track named `{}`", name))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn mark_progress() {
+        let mut all = AllData::new();
+        assert!(all.progress_tracks(0, 1).is_none());
+        assert_eq!(
+            Ok("Vow: [□□□□□□□□□□] 0/10 (dangerous)".to_string()),
+            all.new_track(0, 1, "Vow", "Dangerous".parse().unwrap())
+        );
+        assert!(all.new_track(0, 1, "Vow", Rank::Epic).is_err());
+        assert!("deadly".parse::<Rank>().is_err());
+
+        assert_eq!(
+            Ok("Vow: [■■■■□□□□□□] 4/10 (dangerous)".to_string()),
+            all.mark_progress(0, 1, "Vow", 2)
+        );
+        assert!(all.new_track(0, 1, "Journey", Rank::Extreme).is_ok());
+        assert_eq!(
+            Ok("Journey: [□□□□□□□□□□] 0/10 +2 ticks (extreme)".to_string()),
+            all.mark_progress(0, 1, "Journey", 1)
+        );
+        assert_eq!(
+            Ok("Vow: [■■■■■■■■■■] 10/10 (dangerous)".to_string()),
+            all.mark_progress(0, 1, "Vow", 10)
+        );
+        assert_eq!(Ok(10), all.progress_score(0, 1, "Vow"));
+        assert_eq!(
+            Ok("Vow: [■■■■■■■■■■] 10/10 (dangerous)".to_string()),
+            all.mark_progress(0, 1, "Vow", 3000)
+        );
+        assert_eq!(
+            Ok("Vow: [□□□□□□□□□□] 0/10 (dangerous)".to_string()),
+            all.mark_progress(0, 1, "Vow", i16::MIN)
+        );
+        assert!(all.progress_score(0, 2, "Vow").is_err());
+
+        assert!(all.delete_track(0, 1, "Vow").is_ok());
+        assert_eq!(1, all.progress_tracks(0, 1).unwrap().len());
+    }
+}
@@ -0,0 +1,129 @@
+use std::collections::{BTreeMap, HashMap};
+
+use serde::{Deserialize, Serialize};
+
+use crate::alias::{AllData, Data};
+
+/// Tokens of the players' characters, like bennies or willpower points, spent to push a roll
+#[derive(Serialize, Deserialize, Debug, Default)]
+pub struct Resources {
+    // user id, resource name, amount
+    pub users: HashMap<u64, BTreeMap<String, u64>>,
+}
+
+/// Change of a resource asked by `/resource`
+#[derive(Debug, PartialEq)]
+pub enum ResourceChange {
+    Set(u64),
+    Add(u64),
+    Remove(u64),
+}
+
+impl std::str::FromStr for ResourceChange {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.trim();
+        let parse = |n: &str| {
+            n.trim()
+                .parse::<u64>()
+                .map_err(|_| format!("Bad amount: `{}`", s))
+        };
+        if let Some(n) = s.strip_prefix('+') {
+            parse(n).map(ResourceChange::Add)
+        } else if let Some(n) = s.strip_prefix('-') {
+            parse(n).map(ResourceChange::Remove)
+        } else {
+            parse(s).map(ResourceChange::Set)
+        }
+    }
+}
+
+impl AllData {
+    pub fn resources(&self, chat_id: u64, user_id: u64) -> Option<&BTreeMap<String, u64>> {
+        self.get(&chat_id)
+            .and_then(|data| data.resources.users.get(&user_id))
+            .filter(|resources| !resources.is_empty())
+    }
+
+    /// Returns the new amount
+    pub fn change_resource(
+        &mut self,
+        chat_id: u64,
+        user_id: u64,
+        name: &str,
+        change: ResourceChange,
+    ) -> u64 {
+        let data = self.entry(chat_id).or_insert_with(Data::new);
+        let resources = data.resources.users.entry(user_id).or_default();
+        let amount = resources.entry(name.to_lowercase()).or_insert(0);
+        *amount = match change {
+            ResourceChange::Set(n) => n,
+            ResourceChange::Add(n) => amount.saturating_add(n),
+            ResourceChange::Remove(n) => amount.saturating_sub(n),
+        };
+        let amount = *amount;
+        if amount == 0 {
+            resources.remove(&name.to_lowercase());
+        }
+        amount
+    }
+
+    /// Spend one point of the resource, returns what is left
+    pub fn spend_resource(
+        &mut self,
+        chat_id: u64,
+        user_id: u64,
+        name: &str,
+    ) -> Result<u64, String> {
+        let left = self
+            .resources(chat_id, user_id)
+            .and_then(|resources| resources.get(&name.to_lowercase()))
+            .copied()
+            .unwrap_or(0);
+        if left == 0 {
+            Err(format!("You don't have any {} left", name))
+        } else {
+            Ok(self.change_resource(chat_id, user_id, name, ResourceChange::Remove(1)))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_resource_change() {
+        assert_eq!(Ok(ResourceChange::Set(3)), "3".parse());
+        assert_eq!(Ok(ResourceChange::Add(2)), "+2".parse());
+        assert_eq!(Ok(ResourceChange::Remove(1)), "-1".parse());
+        assert!("many".parse::<ResourceChange>().is_err());
+    }
+
+    #[test]
+    fn spend_resources() {
+        let mut all = AllData::new();
+        assert!(all.resources(0, 1).is_none());
+        assert!(all.spend_resource(0, 1, "benny").is_err());
+
+        assert_eq!(
+            2,
+            all.change_resource(0, 1, "Benny", ResourceChange::Set(2))
+        );
+        assert_eq!(Ok(1), all.spend_resource(0, 1, "benny"));
+        assert_eq!(Ok(0), all.spend_resource(0, 1, "BENNY"));
+        assert!(all.spend_resource(0, 1, "benny").is_err());
+        assert!(all.resources(0, 1).is_none());
+
+        assert_eq!(
+            3,
+            all.change_resource(0, 1, "willpower", ResourceChange::Add(3))
+        );
+        assert_eq!(
+            0,
+            all.change_resource(0, 1, "willpower", ResourceChange::Remove(5))
+        );
+        assert!(all.resources(0, 2).is_none());
+    }
+}
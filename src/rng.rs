@@ -0,0 +1,57 @@
+use std::sync::{Mutex, OnceLock};
+
+use rand::{Rng, RngCore, SeedableRng};
+use rand_chacha::ChaCha20Rng;
+
+/// Source of randomness used for a command, it must be `Send` to be kept across `await`
+pub type DiceRng = Box<dyn RngCore + Send>;
+
+// Set once at startup to make every command reproducible, see `set_global_seed`
+static GLOBAL_RNG: OnceLock<Mutex<ChaCha20Rng>> = OnceLock::new();
+
+/// Make the rolls and shuffles of the whole bot reproducible: with the same seed and the same
+/// commands in the same order, the results are the same. Used to replay bug reports.
+///
+/// Returns `false` if a seed was already set.
+pub fn set_global_seed(seed: u64) -> bool {
+    GLOBAL_RNG
+        .set(Mutex::new(ChaCha20Rng::seed_from_u64(seed)))
+        .is_ok()
+}
+
+/// RNG of a command: unpredictable, unless a global seed was set
+pub fn default_rng() -> DiceRng {
+    match GLOBAL_RNG.get() {
+        Some(global) => seeded_rng(global.lock().unwrap().gen()),
+        None => Box::new(ChaCha20Rng::from_entropy()),
+    }
+}
+
+/// Reproducible RNG: the same seed always gives the same rolls
+pub fn seeded_rng(seed: [u8; 32]) -> DiceRng {
+    Box::new(ChaCha20Rng::from_seed(seed))
+}
+
+/// Reproducible RNG from a number, handy in tests
+#[cfg(test)]
+pub fn rng_from_seed(seed: u64) -> DiceRng {
+    Box::new(ChaCha20Rng::seed_from_u64(seed))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn same_seed_same_rolls() {
+        let roller = caith::Roller::new("10d20 + 4d6").unwrap();
+        let roll = |seed| {
+            roller
+                .roll_with(&mut rng_from_seed(seed))
+                .unwrap()
+                .to_string()
+        };
+        assert_eq!(roll(42), roll(42));
+        assert_ne!(roll(42), roll(43));
+    }
+}
@@ -0,0 +1,525 @@
+use std::{fmt::Write, str::FromStr};
+
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    alias::{AllData, Data},
+    fair::{FairRoll, FairSeed},
+    history::LogEntry,
+    rng::DiceRng,
+};
+
+/// Maximum number of events recorded in a session, later ones are left out of the transcript
+pub const MAX_SESSION_EVENTS: usize = 5000;
+
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EventKind {
+    Roll,
+    Draw,
+    Reveal,
+}
+
+impl EventKind {
+    fn verb(&self) -> &'static str {
+        match self {
+            EventKind::Roll => "rolled",
+            EventKind::Draw => "drew",
+            EventKind::Reveal => "revealed",
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct SessionEvent {
+    pub kind: EventKind,
+    pub entry: LogEntry,
+}
+
+/// Everything rolled, drawn or revealed in a channel between `/session start` and `/session end`
+#[derive(Serialize, Deserialize, Debug, Default)]
+pub struct Session {
+    // seconds since UNIX epoch
+    pub started_at: u64,
+    pub ended_at: Option<u64>,
+    pub events: Vec<SessionEvent>,
+    // only for provably fair sessions
+    #[serde(default)]
+    pub fair: Option<FairSeed>,
+    // every roll of a provably fair session, for `/verify`: not capped like `events`, and with
+    // the secret rolls revealed after the end
+    #[serde(default)]
+    pub fair_rolls: Vec<LogEntry>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ExportFormat {
+    Markdown,
+    Html,
+}
+
+impl ExportFormat {
+    pub fn extension(&self) -> &'static str {
+        match self {
+            ExportFormat::Markdown => "md",
+            ExportFormat::Html => "html",
+        }
+    }
+}
+
+impl FromStr for ExportFormat {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim().to_lowercase().as_str() {
+            "md" | "markdown" => Ok(ExportFormat::Markdown),
+            "html" => Ok(ExportFormat::Html),
+            _ => Err("Unknown format, expected `md` or `html`".to_string()),
+        }
+    }
+}
+
+impl Session {
+    fn new(started_at: u64, fair: bool) -> Self {
+        Session {
+            started_at,
+            ended_at: None,
+            events: Vec::new(),
+            fair: if fair {
+                Some(FairSeed::generate())
+            } else {
+                None
+            },
+            fair_rolls: Vec::new(),
+        }
+    }
+
+    pub fn is_running(&self) -> bool {
+        self.ended_at.is_none()
+    }
+
+    fn was_running_at(&self, timestamp: u64) -> bool {
+        self.started_at <= timestamp && self.ended_at.map_or(true, |ended_at| timestamp <= ended_at)
+    }
+
+    pub fn export(&self, format: ExportFormat, channel_name: &str) -> String {
+        match format {
+            ExportFormat::Markdown => self.to_markdown(channel_name),
+            ExportFormat::Html => self.to_html(channel_name),
+        }
+    }
+
+    fn period(&self) -> String {
+        match self.ended_at {
+            Some(ended_at) => format!(
+                "From {} to {}",
+                format_timestamp(self.started_at),
+                format_timestamp(ended_at)
+            ),
+            None => format!("Since {} (running)", format_timestamp(self.started_at)),
+        }
+    }
+
+    // the seed is only given once the session is over
+    fn fairness(&self) -> Option<String> {
+        self.fair.as_ref().map(|fair| match self.ended_at {
+            Some(_) => format!(
+                "Provably fair session, seed commitment: `{}`, seed: `{}`",
+                fair.commitment, fair.seed
+            ),
+            None => format!(
+                "Provably fair session, seed commitment: `{}`",
+                fair.commitment
+            ),
+        })
+    }
+
+    fn to_markdown(&self, channel_name: &str) -> String {
+        let mut s = format!("# Session of #{}\n\n{}\n\n", channel_name, self.period());
+        if let Some(fairness) = self.fairness() {
+            let _ = write!(s, "{}\n\n", fairness);
+        }
+        if self.events.is_empty() {
+            s.push_str("Nothing happened.\n");
+        }
+        for event in self.events.iter() {
+            let _ = writeln!(s, "- {}", format_event(event));
+        }
+        s
+    }
+
+    fn to_html(&self, channel_name: &str) -> String {
+        let title = format!("Session of #{}", escape_html(channel_name));
+        let mut s = format!(
+            "<!DOCTYPE html>\n<html>\n<head>\n<meta charset=\"utf-8\">\n<title>{0}</title>\n\
+            </head>\n<body>\n<h1>{0}</h1>\n<p>{1}</p>\n",
+            title,
+            self.period()
+        );
+        if let Some(fairness) = self.fairness() {
+            let _ = writeln!(s, "<p>{}</p>", markdown_to_html(&fairness));
+        }
+        if self.events.is_empty() {
+            s.push_str("<p>Nothing happened.</p>\n");
+        } else {
+            s.push_str("<ul>\n");
+            for event in self.events.iter() {
+                let _ = writeln!(s, "<li>{}</li>", markdown_to_html(&format_event(event)));
+            }
+            s.push_str("</ul>\n");
+        }
+        s.push_str("</body>\n</html>\n");
+        s
+    }
+}
+
+// one line of transcript, using Discord's markdown
+fn format_event(event: &SessionEvent) -> String {
+    let entry = &event.entry;
+    let crit = match (entry.crit_max, entry.crit_min) {
+        (true, true) => " 🥳🤬",
+        (true, false) => " 🥳",
+        (false, true) => " 🤬",
+        (false, false) => "",
+    };
+    let expression = if entry.expression.is_empty() {
+        String::new()
+    } else {
+        format!(" `{}`", entry.expression)
+    };
+    let expansion = match &entry.expansion {
+        Some(expansion) => format!(" (`{}`)", expansion),
+        None => String::new(),
+    };
+    let nonce = match &entry.fair {
+        Some(fair) => format!(" [nonce {}]", fair.nonce),
+        None => String::new(),
+    };
+    format!(
+        "{} **{}** {}{}{}{}: {}{}",
+        format_time(entry.timestamp),
+        entry.user_name,
+        event.kind.verb(),
+        expression,
+        expansion,
+        nonce,
+        replace_card_emojis(entry.result.trim_end()).replace('\n', " "),
+        crit
+    )
+}
+
+// cards are printed with Discord's emoji shortcodes
+fn replace_card_emojis(s: &str) -> String {
+    s.replace(":black_joker:", "🃏")
+        .replace(":clubs:", "♣")
+        .replace(":diamonds:", "♦")
+        .replace(":hearts:", "♥")
+        .replace(":spades:", "♠")
+}
+
+fn escape_html(s: &str) -> String {
+    s.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
+// only handles the markdown produced by the bot: `**bold**`, `*italic*` and `code`
+fn markdown_to_html(s: &str) -> String {
+    let s = escape_html(s);
+    let mut html = String::with_capacity(s.len());
+    let (mut bold, mut italic, mut code) = (false, false, false);
+    let mut chars = s.chars().peekable();
+    while let Some(c) = chars.next() {
+        match c {
+            '`' => {
+                html.push_str(if code { "</code>" } else { "<code>" });
+                code = !code;
+            }
+            '*' if !code && chars.peek() == Some(&'*') => {
+                chars.next();
+                html.push_str(if bold { "</strong>" } else { "<strong>" });
+                bold = !bold;
+            }
+            '*' if !code => {
+                html.push_str(if italic { "</em>" } else { "<em>" });
+                italic = !italic;
+            }
+            c => html.push(c),
+        }
+    }
+    html
+}
+
+fn format_time(timestamp: u64) -> String {
+    let secs = timestamp % 86400;
+    format!("{:02}:{:02}", secs / 3600, secs % 3600 / 60)
+}
+
+/// `YYYY-MM-DD HH:MM UTC`
+pub fn format_timestamp(timestamp: u64) -> String {
+    format!("{} {} UTC", format_date(timestamp), format_time(timestamp))
+}
+
+/// `YYYY-MM-DD`
+pub fn format_date(timestamp: u64) -> String {
+    // civil from days, see http://howardhinnant.github.io/date_algorithms.html
+    let z = (timestamp / 86400) as i64 + 719468;
+    let era = z.div_euclid(146097);
+    let doe = z.rem_euclid(146097);
+    let yoe = (doe - doe / 1460 + doe / 36524 - doe / 146096) / 365;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let day = doy - (153 * mp + 2) / 5 + 1;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 };
+    let year = yoe + era * 400 + if month <= 2 { 1 } else { 0 };
+    format!("{:04}-{:02}-{:02}", year, month, day)
+}
+
+impl AllData {
+    pub fn start_session(&mut self, chat_id: u64, channel_id: u64, now: u64, fair: bool) -> String {
+        let data = self.entry(chat_id).or_insert_with(Data::new);
+        match data.sessions.get(&channel_id) {
+            Some(session) if session.is_running() => {
+                "A session is already running in this channel".to_string()
+            }
+            _ => {
+                let session = Session::new(now, fair);
+                let msg = match &session.fair {
+                    Some(fair) => format!(
+                        "Provably fair session started: rolls, draws and reveals of this channel \
+                        are recorded.\nSeed commitment (SHA-256 of the seed revealed at the end): \
+                        `{}`",
+                        fair.commitment
+                    ),
+                    None => {
+                        "Session started: rolls, draws and reveals of this channel are recorded"
+                            .to_string()
+                    }
+                };
+                data.sessions.insert(channel_id, session);
+                msg
+            }
+        }
+    }
+
+    pub fn end_session(&mut self, chat_id: u64, channel_id: u64, now: u64) -> String {
+        match self
+            .get_mut(&chat_id)
+            .and_then(|data| data.sessions.get_mut(&channel_id))
+        {
+            Some(session) if session.is_running() => {
+                session.ended_at = Some(now);
+                let mut msg = format!(
+                    "Session ended with {} events, get the transcript with `/session export md|html`",
+                    session.events.len()
+                );
+                if let Some(fair) = &session.fair {
+                    msg.push_str(&format!(
+                        "\nSeed: `{}`, check the rolls with `/verify [user] <nonce>`",
+                        fair.seed
+                    ));
+                }
+                msg
+            }
+            _ => "No session running in this channel".to_string(),
+        }
+    }
+
+    /// Record the event if a session is running in the channel. The fair rolls done during the
+    /// session are also kept for `/verify`, even when revealed after its end.
+    pub fn record_event(
+        &mut self,
+        chat_id: u64,
+        channel_id: u64,
+        kind: EventKind,
+        entry: LogEntry,
+    ) {
+        if let Some(session) = self
+            .get_mut(&chat_id)
+            .and_then(|data| data.sessions.get_mut(&channel_id))
+        {
+            if session.fair.is_some()
+                && entry.fair.is_some()
+                && session.was_running_at(entry.timestamp)
+            {
+                session.fair_rolls.push(entry.clone());
+            }
+            if session.is_running() && session.events.len() < MAX_SESSION_EVENTS {
+                session.events.push(SessionEvent { kind, entry });
+            }
+        }
+    }
+
+    /// RNG and nonce of the next roll of the user, if a provably fair session is running
+    pub fn fair_roll(
+        &mut self,
+        chat_id: u64,
+        channel_id: u64,
+        user_id: u64,
+    ) -> Option<(DiceRng, u64)> {
+        self.get_mut(&chat_id)
+            .and_then(|data| data.sessions.get_mut(&channel_id))
+            .filter(|session| session.is_running())
+            .and_then(|session| session.fair.as_mut())
+            .map(|fair| fair.next_roll(user_id))
+    }
+
+    /// Seed and logged roll to verify, once the fair session is over
+    pub fn fair_roll_entry(
+        &self,
+        chat_id: u64,
+        channel_id: u64,
+        user_id: u64,
+        nonce: u64,
+    ) -> Result<(&FairSeed, &LogEntry, &FairRoll), String> {
+        let session = self
+            .session(chat_id, channel_id)
+            .ok_or_else(|| "No session recorded in this channel".to_string())?;
+        let fair = session
+            .fair
+            .as_ref()
+            .ok_or_else(|| "The last session of this channel was not provably fair".to_string())?;
+        if session.is_running() {
+            return Err("The seed will be revealed at the end of the session".to_string());
+        }
+        session
+            .fair_rolls
+            .iter()
+            .filter(|entry| entry.user_id == user_id)
+            .find_map(|entry| match &entry.fair {
+                Some(roll) if roll.nonce == nonce => Some((fair, entry, roll)),
+                _ => None,
+            })
+            .ok_or_else(|| format!("No roll with nonce {} for this user", nonce))
+    }
+
+    /// Running or last session of the channel
+    pub fn session(&self, chat_id: u64, channel_id: u64) -> Option<&Session> {
+        self.get(&chat_id)
+            .and_then(|data| data.sessions.get(&channel_id))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn entry(expression: &str, result: &str) -> LogEntry {
+        let mut entry = LogEntry::new(1, "Geob", expression, result);
+        entry.timestamp = 3600 + 5 * 60;
+        entry
+    }
+
+    #[test]
+    fn records_only_running_session() {
+        let mut all = AllData::new();
+        all.record_event(0, 10, EventKind::Roll, entry("1d6", "1"));
+        assert!(all.session(0, 10).is_none());
+
+        all.start_session(0, 10, 0, false);
+        all.record_event(0, 10, EventKind::Roll, entry("1d6", "2"));
+        all.record_event(0, 11, EventKind::Roll, entry("1d6", "3"));
+        all.end_session(0, 10, 10);
+        all.record_event(0, 10, EventKind::Roll, entry("1d6", "4"));
+
+        let session = all.session(0, 10).unwrap();
+        assert!(!session.is_running());
+        assert_eq!(1, session.events.len());
+        assert_eq!("2", session.events[0].entry.result);
+    }
+
+    #[test]
+    fn export_markdown_and_html() {
+        let mut all = AllData::new();
+        all.start_session(0, 10, 0, false);
+        let mut roll = entry("1d20 + 5", "`[20] + 5` Result: **25**");
+        roll.crit_max = true;
+        all.record_event(0, 10, EventKind::Roll, roll);
+        all.record_event(0, 10, EventKind::Draw, entry("2", "K:hearts:, 3:spades:"));
+        all.end_session(0, 10, 86400 * 365);
+
+        let session = all.session(0, 10).unwrap();
+        let md = session.export(ExportFormat::Markdown, "game");
+        assert_eq!(
+            "# Session of #game\n\nFrom 1970-01-01 00:00 UTC to 1971-01-01 00:00 UTC\n\n\
+            - 01:05 **Geob** rolled `1d20 + 5`: `[20] + 5` Result: **25** 🥳\n\
+            - 01:05 **Geob** drew `2`: K♥, 3♠\n",
+            md
+        );
+
+        let html = session.export(ExportFormat::Html, "<game>");
+        assert!(html.contains("<title>Session of #&lt;game&gt;</title>"));
+        assert!(html.contains(
+            "<li>01:05 <strong>Geob</strong> rolled <code>1d20 + 5</code>: \
+            <code>[20] + 5</code> Result: <strong>25</strong> 🥳</li>"
+        ));
+    }
+
+    #[test]
+    fn fair_session() {
+        let mut all = AllData::new();
+        assert!(all.fair_roll(0, 10, 1).is_none());
+        all.start_session(0, 10, 0, true);
+        let (_, nonce) = all.fair_roll(0, 10, 1).unwrap();
+        let mut roll = entry("1d6", "3");
+        roll.fair = Some(FairRoll {
+            nonce,
+            input: "1d6".to_string(),
+        });
+        all.record_event(0, 10, EventKind::Roll, roll);
+        assert!(all.fair_roll_entry(0, 10, 1, nonce).is_err());
+
+        let msg = all.end_session(0, 10, 7200);
+        let (seed, entry, roll) = all.fair_roll_entry(0, 10, 1, 1).unwrap();
+        assert!(msg.contains(&seed.seed));
+        assert_eq!("3", entry.result);
+        assert_eq!("1d6", roll.input);
+        assert!(all.fair_roll_entry(0, 10, 1, 2).is_err());
+        assert!(all.fair_roll_entry(0, 10, 2, 1).is_err());
+        assert!(all.fair_roll(0, 10, 1).is_none());
+    }
+
+    #[test]
+    fn fair_rolls_are_verifiable_past_the_transcript() {
+        let mut all = AllData::new();
+        all.start_session(0, 10, 0, true);
+        for _ in 0..MAX_SESSION_EVENTS {
+            all.record_event(0, 10, EventKind::Draw, entry("1", "K:hearts:"));
+        }
+        let fair_entry = |all: &mut AllData, result: &str| {
+            let (_, nonce) = all.fair_roll(0, 10, 1).unwrap();
+            let mut roll = entry("1d6", result);
+            roll.fair = Some(FairRoll {
+                nonce,
+                input: "1d6".to_string(),
+            });
+            roll
+        };
+        let roll = fair_entry(&mut all, "4");
+        all.record_event(0, 10, EventKind::Roll, roll);
+        // a secret roll revealed after the end of the session
+        let secret = fair_entry(&mut all, "5");
+        all.end_session(0, 10, 7200);
+        all.record_event(0, 10, EventKind::Roll, secret);
+        // rolled after the end, it doesn't belong to the session
+        let mut late = entry("1d6", "6");
+        late.timestamp = 7201;
+        late.fair = Some(FairRoll {
+            nonce: 3,
+            input: "1d6".to_string(),
+        });
+        all.record_event(0, 10, EventKind::Roll, late);
+
+        assert_eq!(MAX_SESSION_EVENTS, all.session(0, 10).unwrap().events.len());
+        assert_eq!("4", all.fair_roll_entry(0, 10, 1, 1).unwrap().1.result);
+        assert_eq!("5", all.fair_roll_entry(0, 10, 1, 2).unwrap().1.result);
+        assert!(all.fair_roll_entry(0, 10, 1, 3).is_err());
+    }
+
+    #[test]
+    fn date_formatting() {
+        assert_eq!("1970-01-01 00:00 UTC", format_timestamp(0));
+        assert_eq!("2021-10-18 20:15 UTC", format_timestamp(1634588100));
+        assert_eq!("2024-02-29", format_date(1709164800));
+    }
+}
@@ -0,0 +1,127 @@
+use std::{collections::HashMap, str::FromStr};
+
+use serde::{Deserialize, Serialize};
+
+use crate::alias::{AllData, Data};
+
+/// How roll results are displayed
+#[derive(Serialize, Deserialize, Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum OutputFormat {
+    #[default]
+    Text,
+    Embed,
+}
+
+impl FromStr for OutputFormat {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim().to_lowercase().as_str() {
+            "text" => Ok(OutputFormat::Text),
+            "embed" => Ok(OutputFormat::Embed),
+            _ => Err("Unknown format, expected `text` or `embed`".to_string()),
+        }
+    }
+}
+
+impl std::fmt::Display for OutputFormat {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            OutputFormat::Text => write!(f, "text"),
+            OutputFormat::Embed => write!(f, "embed"),
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Default)]
+pub struct Settings {
+    // format used when the user didn't choose one
+    pub format: OutputFormat,
+    // user id, format chosen by the user
+    pub users_format: HashMap<u64, OutputFormat>,
+    // role receiving the blind rolls
+    #[serde(default)]
+    pub gm_role: Option<u64>,
+}
+
+impl AllData {
+    pub fn output_format(&self, chat_id: u64, user_id: u64) -> OutputFormat {
+        match self.get(&chat_id) {
+            Some(data) => match data.settings.users_format.get(&user_id) {
+                Some(format) => *format,
+                None => data.settings.format,
+            },
+            None => OutputFormat::default(),
+        }
+    }
+
+    pub fn set_output_format(
+        &mut self,
+        format: OutputFormat,
+        chat_id: u64,
+        user_id: Option<u64>,
+    ) -> String {
+        let data = self.entry(chat_id).or_insert_with(Data::new);
+        match user_id {
+            Some(user_id) => {
+                data.settings.users_format.insert(user_id, format);
+                format!("Your rolls will be displayed as {}", format)
+            }
+            None => {
+                data.settings.format = format;
+                format!("Rolls will be displayed as {} by default", format)
+            }
+        }
+    }
+
+    pub fn gm_role(&self, chat_id: u64) -> Option<u64> {
+        self.get(&chat_id).and_then(|data| data.settings.gm_role)
+    }
+
+    pub fn set_gm_role(&mut self, chat_id: u64, role_id: Option<u64>) -> String {
+        let data = self.entry(chat_id).or_insert_with(Data::new);
+        data.settings.gm_role = role_id;
+        match role_id {
+            Some(role_id) => format!("Blind rolls will be sent to <@&{}>", role_id),
+            None => "GM role removed, blind rolls are disabled".to_string(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn user_format_override_default() {
+        let mut all = AllData::new();
+        assert_eq!(OutputFormat::Text, all.output_format(0, 1));
+
+        all.set_output_format(OutputFormat::Embed, 0, None);
+        assert_eq!(OutputFormat::Embed, all.output_format(0, 1));
+
+        all.set_output_format(OutputFormat::Text, 0, Some(1));
+        assert_eq!(OutputFormat::Text, all.output_format(0, 1));
+        assert_eq!(OutputFormat::Embed, all.output_format(0, 2));
+    }
+
+    #[test]
+    fn set_gm_role() {
+        let mut all = AllData::new();
+        assert_eq!(None, all.gm_role(0));
+        all.set_gm_role(0, Some(42));
+        assert_eq!(Some(42), all.gm_role(0));
+        assert_eq!(None, all.gm_role(1));
+        all.set_gm_role(0, None);
+        assert_eq!(None, all.gm_role(0));
+    }
+
+    #[test]
+    fn load_data_without_settings() {
+        let data: Data =
+            ron::de::from_str("(global_aliases: {\"ATT\": \"d20\"}, users_aliases: {})").unwrap();
+        assert_eq!(OutputFormat::Text, data.settings.format);
+        assert_eq!(None, data.settings.gm_role);
+        assert_eq!(Some(&"d20".to_string()), data.global_aliases.get("ATT"));
+    }
+}
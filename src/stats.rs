@@ -0,0 +1,588 @@
+use std::{
+    collections::{BTreeMap, HashMap, HashSet},
+    fmt::Write,
+};
+
+use caith::{Critic, RollHistory, RollResult, RollResultType, Roller, SingleRollResult};
+use serde::{Deserialize, Serialize};
+
+use crate::alias::{AllData, Data};
+
+/// Dice statistics of a user
+#[derive(Serialize, Deserialize, Debug, Default, PartialEq)]
+pub struct UserStats {
+    pub rolls: u64,
+    // number of rolls with at least one dice on its max
+    pub crit_max: u64,
+    // number of rolls with at least one dice on 1
+    pub crit_min: u64,
+    // dice sides, count of each rolled face
+    pub faces: BTreeMap<u64, BTreeMap<u64, u64>>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Default)]
+pub struct Stats {
+    // user id, stats
+    pub users: HashMap<u64, UserStats>,
+    // users who don't want their rolls to be counted
+    pub opt_out: HashSet<u64>,
+}
+
+/// A dice of an expression with its options, ex: `5d6 t6 f1`
+#[derive(Debug, PartialEq)]
+pub struct DiceTerm {
+    pub sides: u64,
+    /// everything after the sides, ex: ` t6 f1` for `5d6 t6 f1`
+    pub options: String,
+}
+
+/// Dices of the expression with their options, in rolling order. Fudge dices are ignored as
+/// they don't appear as a `RollHistory::Roll`.
+pub fn dice_terms(expr: &str) -> Vec<DiceTerm> {
+    let expr = match expr.find(':') {
+        Some(idx) => &expr[..idx],
+        None => expr,
+    };
+    expr.split(|c: char| "+-*/()^#".contains(c))
+        .filter_map(|operand| {
+            // a dice is `NdS` at the start of an operand, everything after it is an option, even
+            // when separated by spaces like the `d1` of `4d6 d1`
+            let operand = operand.trim();
+            let dice = operand.trim_start_matches(|c: char| c.is_ascii_digit());
+            let dice = dice.strip_prefix(['d', 'D'])?;
+            let len = dice.chars().take_while(|c| c.is_ascii_digit()).count();
+            Some(DiceTerm {
+                sides: dice[..len].parse().ok()?,
+                options: dice[len..].to_string(),
+            })
+        })
+        .collect()
+}
+
+/// Number of sides of each dice of the expression, in rolling order
+pub fn dice_sides(expr: &str) -> Vec<u64> {
+    dice_terms(expr).iter().map(|term| term.sides).collect()
+}
+
+// The options of a dice which change what caith records in the history of the roll
+#[derive(Debug, PartialEq)]
+enum DiceOption {
+    // `e6` explodes once, `ie6` or `!` again and again
+    Explode { value: u64, again: bool },
+    // `r1` rerolls once, `ir1` until the dice is above the value
+    Reroll { value: u64, again: bool },
+    // `K`, `k`, `D` or `d` and the number of dices kept or dropped
+    Select(char, usize),
+    // `t`, `tt` and `f`, or a list of targets like `t[5,6]`
+    Target { list: bool },
+}
+
+// The number starting at `chars[*idx]`, moving `idx` after it
+fn take_number(chars: &[char], idx: &mut usize) -> Option<u64> {
+    let len = chars[*idx..]
+        .iter()
+        .take_while(|c| c.is_ascii_digit())
+        .count();
+    let number = chars[*idx..*idx + len]
+        .iter()
+        .collect::<String>()
+        .parse()
+        .ok();
+    *idx += len;
+    number
+}
+
+impl DiceTerm {
+    fn parse_options(&self) -> Vec<DiceOption> {
+        let chars: Vec<char> = self
+            .options
+            .chars()
+            .filter(|c| !c.is_whitespace())
+            .collect();
+        let mut options = Vec::new();
+        let mut idx = 0;
+        while idx < chars.len() {
+            let (c, next) = (chars[idx], chars.get(idx + 1).copied());
+            idx += 1;
+            let option = match (c, next) {
+                ('i', Some('e')) | ('i', Some('r')) => {
+                    idx += 1;
+                    let value = take_number(&chars, &mut idx);
+                    if next == Some('e') {
+                        DiceOption::Explode {
+                            value: value.unwrap_or(self.sides),
+                            again: true,
+                        }
+                    } else {
+                        DiceOption::Reroll {
+                            value: value.unwrap_or_default(),
+                            again: true,
+                        }
+                    }
+                }
+                ('!', _) | ('e', _) => DiceOption::Explode {
+                    value: take_number(&chars, &mut idx).unwrap_or(self.sides),
+                    again: c == '!',
+                },
+                ('r', _) => DiceOption::Reroll {
+                    value: take_number(&chars, &mut idx).unwrap_or_default(),
+                    again: false,
+                },
+                ('K', _) | ('k', _) | ('D', _) | ('d', _) => DiceOption::Select(
+                    c,
+                    take_number(&chars, &mut idx).unwrap_or_default() as usize,
+                ),
+                ('t', Some('[')) => {
+                    idx += chars[idx..].iter().take_while(|c| **c != ']').count() + 1;
+                    DiceOption::Target { list: true }
+                }
+                ('t', _) | ('f', _) => {
+                    if next == Some('t') {
+                        idx += 1;
+                    }
+                    take_number(&chars, &mut idx);
+                    DiceOption::Target { list: false }
+                }
+                _ => continue,
+            };
+            options.push(option);
+        }
+        options
+    }
+
+    /// Whether the options roll more dices: explosions and rerolls
+    pub fn rolls_again(&self) -> bool {
+        self.parse_options().iter().any(|option| {
+            matches!(
+                option,
+                DiceOption::Explode { .. } | DiceOption::Reroll { .. }
+            )
+        })
+    }
+
+    // The dices rolled for this term, taking its groups from the history as caith records them.
+    // `None` if the groups can't be attributed: a reroll before the dices are recorded only adds
+    // a group if something is rerolled.
+    fn rolled_dices(&self, groups: &mut impl Iterator<Item = Vec<u64>>) -> Option<Vec<u64>> {
+        let options = self.parse_options();
+        if options.is_empty() {
+            return groups.next();
+        }
+        let mut rolled = Vec::new();
+        // the current dices, `None` until they are recorded
+        let mut current: Option<Vec<u64>> = None;
+        let mut previous: Option<&DiceOption> = None;
+        for option in options.iter() {
+            // the current dices are recorded before some options, only counted the first time
+            let records = match option {
+                DiceOption::Explode { .. } => !matches!(previous, Some(DiceOption::Explode { .. })),
+                DiceOption::Reroll { .. } => false,
+                DiceOption::Select(_, _) => current.is_none(),
+                DiceOption::Target { list } => {
+                    *list || !matches!(previous, Some(DiceOption::Target { list: false }))
+                }
+            };
+            if records {
+                let group = groups.next()?;
+                if current.is_none() {
+                    rolled.extend_from_slice(&group);
+                    current = Some(group);
+                }
+            }
+            match option {
+                DiceOption::Explode { value, again } => {
+                    let count = |dices: &[u64]| dices.iter().filter(|d| *d >= value).count();
+                    let mut exploding = count(current.as_deref().unwrap_or_default());
+                    let mut exploded = None;
+                    while exploding > 0 {
+                        let group = groups.next()?;
+                        exploding = if *again { count(&group) } else { 0 };
+                        rolled.extend_from_slice(&group);
+                        exploded = Some(group);
+                    }
+                    if exploded.is_some() || *again {
+                        current = Some(exploded.unwrap_or_default());
+                    }
+                }
+                DiceOption::Reroll { value, again } => {
+                    let dices = current.as_ref()?;
+                    if dices.iter().any(|d| d <= value) {
+                        let group = groups.next()?;
+                        // the dices above the value are kept, the other ones are new. Dices
+                        // rerolled again and again are not counted: they are all above the value
+                        if !again {
+                            let mut new = group.clone();
+                            for kept in dices.iter().filter(|d| *d > value) {
+                                if let Some(idx) = new.iter().position(|d| d == kept) {
+                                    new.swap_remove(idx);
+                                }
+                            }
+                            rolled.extend(new);
+                        }
+                        current = Some(group);
+                    }
+                }
+                DiceOption::Select(kind, nb) => {
+                    if let Some(dices) = current.as_mut() {
+                        dices.sort_unstable();
+                        let (nb, len) = ((*nb).min(dices.len()), dices.len());
+                        *dices = match kind {
+                            'K' => dices[len - nb..].to_vec(),
+                            'k' => dices[..nb].to_vec(),
+                            'D' => dices[..len - nb].to_vec(),
+                            _ => dices[nb..].to_vec(),
+                        };
+                    }
+                }
+                DiceOption::Target { .. } => (),
+            }
+            previous = Some(option);
+        }
+        Some(rolled)
+    }
+}
+
+/// The dices of a roll's history with their number of sides. Only the rolled dices are counted:
+/// the groups recording the same dices again are skipped. Stops at the first group which can't
+/// be attributed to a dice.
+pub fn rolled_dices(terms: &[DiceTerm], history: &[RollHistory]) -> Vec<(u64, Vec<u64>)> {
+    let mut groups = history.iter().filter_map(|h| match h {
+        RollHistory::Roll(dices) => Some(dices.iter().map(|d| d.res).collect()),
+        _ => None,
+    });
+    let mut rolled = Vec::new();
+    for term in terms {
+        match term.rolled_dices(&mut groups) {
+            Some(dices) => rolled.push((term.sides, dices)),
+            None => break,
+        }
+    }
+    rolled
+}
+
+impl UserStats {
+    pub fn add_roll(&mut self, roller: &Roller, res: &RollResult) {
+        self.rolls += 1;
+        let terms = dice_terms(roller.as_str());
+        match res.get_result() {
+            RollResultType::Single(res) => self.add_single(res, &terms),
+            RollResultType::Repeated(res) => {
+                res.iter().for_each(|res| self.add_single(res, &terms))
+            }
+        }
+        if let Ok(crit) = crate::search_crit(res) {
+            if crit.contains(&Critic::Max) {
+                self.crit_max += 1;
+            }
+            if crit.contains(&Critic::Min) {
+                self.crit_min += 1;
+            }
+        }
+    }
+
+    fn add_single(&mut self, res: &SingleRollResult, terms: &[DiceTerm]) {
+        for (sides, dices) in rolled_dices(terms, res.get_history()) {
+            let faces = self.faces.entry(sides).or_default();
+            for dice in dices {
+                *faces.entry(dice).or_insert(0) += 1;
+            }
+        }
+    }
+
+    /// Human readable stats, with the face counts for dices up to d20
+    pub fn report(&self) -> String {
+        let mut s = format!(
+            "{} rolls, {} with a critic max 🥳, {} with a critic min 🤬\n",
+            self.rolls, self.crit_max, self.crit_min
+        );
+        for (sides, faces) in self.faces.iter() {
+            let nb: u64 = faces.values().sum();
+            let total: u64 = faces.iter().map(|(face, count)| face * count).sum();
+            let _ = writeln!(
+                s,
+                "**d{}**: {} dices, average **{:.2}** (expected {:.1})",
+                sides,
+                nb,
+                total as f64 / nb as f64,
+                (*sides as f64 + 1.0) / 2.0
+            );
+            if *sides <= 20 {
+                let counts: Vec<String> = (1..=*sides)
+                    .map(|face| format!("{}: {}", face, faces.get(&face).unwrap_or(&0)))
+                    .collect();
+                let _ = writeln!(s, "`{}`", counts.join(", "));
+            }
+        }
+        s
+    }
+
+    /// Chi-squared test of each dice size
+    pub fn fairness_report(&self) -> String {
+        let mut s = String::new();
+        for (sides, faces) in self.faces.iter() {
+            let nb: u64 = faces.values().sum();
+            let (stat, p_value) = chi_squared(*sides, faces);
+            let _ = write!(
+                s,
+                "**d{}**: {} dices, χ² = {:.2} ({} degrees of freedom), p-value = **{:.4}**",
+                sides,
+                nb,
+                stat,
+                sides.saturating_sub(1),
+                p_value
+            );
+            // the test is not reliable below 5 expected occurrences of each face
+            if nb < 5 * sides {
+                s.push_str(" (not enough rolls to be meaningful)");
+            }
+            s.push('\n');
+        }
+        if s.is_empty() {
+            s.push_str("No dice rolled\n");
+        }
+        s
+    }
+}
+
+/// Chi-squared goodness-of-fit of the rolled faces against a fair dice: statistic and p-value
+pub fn chi_squared(sides: u64, faces: &BTreeMap<u64, u64>) -> (f64, f64) {
+    let nb: u64 = faces.values().sum();
+    if sides < 2 || nb == 0 {
+        return (0.0, 1.0);
+    }
+    let expected = nb as f64 / sides as f64;
+    let stat = (1..=sides)
+        .map(|face| (*faces.get(&face).unwrap_or(&0) as f64 - expected).powi(2) / expected)
+        .sum::<f64>();
+    let freedom = (sides - 1) as f64;
+    (stat, gamma_q(freedom / 2.0, stat / 2.0))
+}
+
+// Lanczos approximation
+fn ln_gamma(x: f64) -> f64 {
+    const COEFS: [f64; 6] = [
+        76.18009172947146,
+        -86.50532032941677,
+        24.01409824083091,
+        -1.231739572450155,
+        0.1208650973866179e-2,
+        -0.5395239384953e-5,
+    ];
+    let tmp = x + 5.5 - (x + 0.5) * (x + 5.5).ln();
+    let ser = COEFS
+        .iter()
+        .enumerate()
+        .fold(1.000000000190015, |acc, (i, c)| {
+            acc + c / (x + 1.0 + i as f64)
+        });
+    -tmp + (2.5066282746310005 * ser / x).ln()
+}
+
+// Regularized upper incomplete gamma function Q(a, x), see Numerical Recipes 6.2
+fn gamma_q(a: f64, x: f64) -> f64 {
+    const EPS: f64 = 1e-12;
+    const MAX_ITER: usize = 1000;
+    if x <= 0.0 {
+        return 1.0;
+    }
+    let ln_prefix = -x + a * x.ln() - ln_gamma(a);
+    if x < a + 1.0 {
+        // series representation of P(a, x)
+        let mut term = 1.0 / a;
+        let mut sum = term;
+        for n in 1..MAX_ITER {
+            term *= x / (a + n as f64);
+            sum += term;
+            if term.abs() < sum.abs() * EPS {
+                break;
+            }
+        }
+        1.0 - sum * ln_prefix.exp()
+    } else {
+        // continued fraction representation of Q(a, x)
+        let tiny = f64::MIN_POSITIVE / EPS;
+        let mut b = x + 1.0 - a;
+        let mut c = 1.0 / tiny;
+        let mut d = 1.0 / b;
+        let mut h = d;
+        for i in 1..MAX_ITER {
+            let an = -(i as f64) * (i as f64 - a);
+            b += 2.0;
+            d = an * d + b;
+            if d.abs() < tiny {
+                d = tiny;
+            }
+            c = b + an / c;
+            if c.abs() < tiny {
+                c = tiny;
+            }
+            d = 1.0 / d;
+            let delta = d * c;
+            h *= delta;
+            if (delta - 1.0).abs() < EPS {
+                break;
+            }
+        }
+        ln_prefix.exp() * h
+    }
+}
+
+impl AllData {
+    pub fn record_stats(&mut self, chat_id: u64, user_id: u64, roller: &Roller, res: &RollResult) {
+        let data = self.entry(chat_id).or_insert_with(Data::new);
+        if !data.stats.opt_out.contains(&user_id) {
+            data.stats
+                .users
+                .entry(user_id)
+                .or_default()
+                .add_roll(roller, res);
+        }
+    }
+
+    pub fn user_stats(&self, chat_id: u64, user_id: u64) -> Option<&UserStats> {
+        self.get(&chat_id)
+            .and_then(|data| data.stats.users.get(&user_id))
+    }
+
+    /// Opting out also forgets the collected stats
+    pub fn set_stats_opt_out(&mut self, chat_id: u64, user_id: u64, opt_out: bool) -> String {
+        let data = self.entry(chat_id).or_insert_with(Data::new);
+        if opt_out {
+            data.stats.opt_out.insert(user_id);
+            data.stats.users.remove(&user_id);
+            "Your rolls won't be counted anymore and your stats were deleted".to_string()
+        } else {
+            data.stats.opt_out.remove(&user_id);
+            "Your rolls will be counted in your stats".to_string()
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::rng::rng_from_seed;
+
+    #[test]
+    fn sides_of_expression() {
+        assert_eq!(vec![20, 6], dice_sides("1d20 + 2d6 + 3 : 1d8 damage"));
+        assert_eq!(vec![10], dice_sides("6d10 t8"));
+        assert_eq!(vec![6, 4], dice_sides("4dF + (d6 - 1d4)"));
+        assert_eq!(vec![6, 10], dice_sides("4d6d1K2 * 2d10e10"));
+        assert_eq!(vec![6, 8], dice_sides("4d6 d1 + 1d8"));
+        assert_eq!(vec![10, 6], dice_sides("6d10 t8 f1 + 2d6 K1"));
+        assert!(dice_sides("3 + 4").is_empty());
+    }
+
+    #[test]
+    fn explosions_and_rerolls_count_for_their_dice() {
+        let rolled = |expr: &str, seed| {
+            let res = Roller::new(expr)
+                .unwrap()
+                .roll_with(&mut rng_from_seed(seed))
+                .unwrap();
+            rolled_dices(&dice_terms(expr), res.as_single().unwrap().get_history())
+        };
+        // `[6, 2, 1][6] + [3]`: the explosion is a d6
+        assert_eq!(
+            vec![(6, vec![6, 2, 1, 6]), (8, vec![3])],
+            rolled("3d6 e6 + 1d8", 0)
+        );
+        // `[6, 6, 2, 1][2, 1] + [3]`
+        assert_eq!(
+            vec![(6, vec![6, 6, 2, 1, 2, 1]), (8, vec![3])],
+            rolled("4d6 ie6 + 1d8", 0)
+        );
+        // `[5, 4, 1, 1][6, 5, 5, 4] + [7]`: the two ones were rerolled into 6 and 5
+        assert_eq!(
+            vec![(6, vec![5, 4, 1, 1, 6, 5]), (8, vec![7])],
+            rolled("4d6 K4 r2 + 1d8", 2)
+        );
+        // the d6 are only recorded when something is rerolled, nothing after can be attributed
+        assert_eq!(vec![(8, vec![1])], rolled("1d8 + 4d6 r2 + 1d4", 0));
+    }
+
+    #[test]
+    fn count_faces_and_crits() {
+        let mut stats = UserStats::default();
+        let roller = Roller::new("(1d4 + 3d6) ^ 5").unwrap();
+        let res = roller.roll_with(&mut rng_from_seed(1)).unwrap();
+        stats.add_roll(&roller, &res);
+
+        assert_eq!(1, stats.rolls);
+        assert_eq!(5, stats.faces[&4].values().sum::<u64>());
+        assert_eq!(15, stats.faces[&6].values().sum::<u64>());
+        assert!(stats.faces[&4].keys().all(|face| (1..=4).contains(face)));
+
+        let has_crit = |crit| {
+            res.as_repeated().unwrap().iter().any(|r| {
+                r.get_history().iter().any(|h| match h {
+                    RollHistory::Roll(dices) => dices.iter().any(|d| d.crit == crit),
+                    _ => false,
+                })
+            })
+        };
+        assert_eq!(has_crit(Critic::Max) as u64, stats.crit_max);
+        assert_eq!(has_crit(Critic::Min) as u64, stats.crit_min);
+    }
+
+    fn assert_close(expected: f64, value: f64) {
+        assert!(
+            (expected - value).abs() < 1e-3,
+            "expected {}, got {}",
+            expected,
+            value
+        );
+    }
+
+    #[test]
+    fn chi_squared_p_values() {
+        // critical values at 5% from the chi-squared table
+        assert_close(0.05, gamma_q(0.5, 3.841 / 2.0));
+        assert_close(0.05, gamma_q(5.0 / 2.0, 11.070 / 2.0));
+        assert_close(0.05, gamma_q(19.0 / 2.0, 30.144 / 2.0));
+        assert_close(0.01, gamma_q(19.0 / 2.0, 36.191 / 2.0));
+
+        let fair: BTreeMap<u64, u64> = (1..=6).map(|face| (face, 100)).collect();
+        assert_eq!((0.0, 1.0), chi_squared(6, &fair));
+
+        let loaded: BTreeMap<u64, u64> = (1..=6).map(|face| (face, face * 20)).collect();
+        let (stat, p_value) = chi_squared(6, &loaded);
+        assert_close(100.0, stat);
+        assert!(p_value < 1e-10);
+    }
+
+    #[test]
+    fn fair_dices_pass_self_test() {
+        let mut stats = UserStats::default();
+        let roller = Roller::new("2d6 + 1d20").unwrap();
+        let mut rng = rng_from_seed(2);
+        for _ in 0..2000 {
+            stats.add_roll(&roller, &roller.roll_with(&mut rng).unwrap());
+        }
+        for (sides, faces) in stats.faces.iter() {
+            // fails once in a million runs on a fair dice
+            assert!(chi_squared(*sides, faces).1 > 1e-6);
+        }
+        let report = stats.fairness_report();
+        assert!(report.starts_with("**d6**: 4000 dices, χ² = "));
+        assert!(report.contains("**d20**: 2000 dices"));
+    }
+
+    #[test]
+    fn opt_out_deletes_stats() {
+        let mut all = AllData::new();
+        let roller = Roller::new("1d6").unwrap();
+        let res = roller.roll_with(&mut rng_from_seed(3)).unwrap();
+        all.record_stats(0, 1, &roller, &res);
+        assert_eq!(1, all.user_stats(0, 1).unwrap().rolls);
+
+        all.set_stats_opt_out(0, 1, true);
+        all.record_stats(0, 1, &roller, &res);
+        assert!(all.user_stats(0, 1).is_none());
+
+        all.set_stats_opt_out(0, 1, false);
+        all.record_stats(0, 1, &roller, &res);
+        assert_eq!(1, all.user_stats(0, 1).unwrap().rolls);
+    }
+}
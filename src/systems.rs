@@ -0,0 +1,155 @@
+use std::collections::HashSet;
+
+use caith::{Critic, RollResult};
+
+mod cde;
+mod coc;
+mod fitd;
+mod genesys;
+mod iron;
+pub mod oracle;
+mod ova;
+mod pbta;
+mod pools;
+mod rk;
+mod sw;
+pub mod yze;
+
+/// Result of a roll once interpreted by a game system
+pub struct Outcome {
+    // `None` when the system doesn't give back a `RollResult`
+    pub res: Option<RollResult>,
+    pub text: String,
+}
+
+impl Outcome {
+    pub fn new(res: RollResult) -> Self {
+        Outcome {
+            text: res.to_string(),
+            res: Some(res),
+        }
+    }
+
+    pub fn text(text: String) -> Self {
+        Outcome { res: None, text }
+    }
+}
+
+/// Rules of a game, called in a roll as `name(parameters)`.
+///
+/// The parameters are parsed into an expression rolled by caith, then the result is interpreted
+/// by the system. To add a game, implement this trait in a module of `systems` and add it to
+/// `SYSTEMS`.
+pub trait GameSystem: Sync {
+    /// Name used to call the system, ex: `ova`
+    fn name(&self) -> &'static str;
+
+    /// Call syntax, ex: `ova(number)`
+    fn syntax(&self) -> &'static str;
+
+    /// Shown in `/help systems`
+    fn description(&self) -> &'static str;
+
+    /// The expression to roll for these parameters
+    fn expression(&self, params: &str) -> Result<String, String>;
+
+    /// The outcome of the roll of `expression(params)`
+    fn interpret(&self, params: &str, res: RollResult) -> Result<Outcome, String>;
+
+    /// Critics to react with, none by default as the system's outcome tells what matters
+    fn crit(&self, _params: &str, _res: &RollResult) -> HashSet<Critic> {
+        HashSet::new()
+    }
+}
+
+static SYSTEMS: &[&dyn GameSystem] = &[
+    &cde::Cde,
+    &coc::Coc,
+    &pools::Exalted,
+    &fitd::Fitd,
+    &genesys::Genesys,
+    &iron::Iron,
+    &iron::IronProgress,
+    &oracle::Oracle,
+    &ova::Ova,
+    &pbta::Pbta,
+    &rk::Rk,
+    &pools::Shadowrun,
+    &sw::Sw,
+    &pools::Wod,
+    &yze::Yze,
+];
+
+/// The system called by the expression, with the parameters of the call
+pub fn parse_call(expression: &str) -> Option<(&'static dyn GameSystem, &str)> {
+    let expression = expression.trim();
+    let open = expression.find('(')?;
+    let params = expression[open + 1..].strip_suffix(')')?;
+    let name = expression[..open].trim();
+    SYSTEMS
+        .iter()
+        .find(|system| system.name().eq_ignore_ascii_case(name))
+        .map(|system| (*system, params))
+}
+
+/// List of the systems for `/help systems`
+pub fn help() -> String {
+    SYSTEMS.iter().fold(
+        "Game systems, usable in `/roll` and in aliases:\n".to_string(),
+        |mut s, system| {
+            s.push_str(&format!(
+                "- `{}`: {}\n",
+                system.syntax(),
+                system.description()
+            ));
+            s
+        },
+    )
+}
+
+/// The roll followed by its outcome for the system, ex: "`[4, 6] + 2` = **12** → Strong hit"
+fn with_outcome(res: &RollResult, outcome: &str) -> String {
+    let mut s = match res.as_single() {
+        Some(single) => format!(
+            "`{}` = **{}** → {}",
+            single.to_string_history(),
+            single.get_total(),
+            outcome
+        ),
+        None => format!("{} → {}", res, outcome),
+    };
+    if let Some(reason) = res.get_reason() {
+        s.push_str(&format!(", Reason: `{}`", reason));
+    }
+    s
+}
+
+/// Error of a system's parameters
+fn syntax_error(system: &dyn GameSystem) -> String {
+    format!("Syntax error, expected: `{}`", system.syntax())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn find_called_system() {
+        let (system, params) = parse_call("ova(-3)").unwrap();
+        assert_eq!(("ova", "-3"), (system.name(), params));
+        let (system, params) = parse_call(" CDE (4, feu) ").unwrap();
+        assert_eq!(("cde", "4, feu"), (system.name(), params));
+
+        assert!(parse_call("(2d6) ^ 2").is_none());
+        assert!(parse_call("unknown(3)").is_none());
+        assert!(parse_call("ova(3").is_none());
+    }
+
+    #[test]
+    fn help_lists_systems() {
+        let help = help();
+        assert!(SYSTEMS
+            .iter()
+            .all(|system| help.contains(&format!("`{}`", system.syntax()))));
+    }
+}
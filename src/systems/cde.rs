@@ -0,0 +1,51 @@
+use caith::RollResult;
+
+use super::{syntax_error, GameSystem, Outcome};
+
+/// Hong Kong : Chroniques de l'étrange
+pub struct Cde;
+
+impl Cde {
+    fn params<'a>(&self, params: &'a str) -> Result<(u32, &'a str), String> {
+        let (number, element) = params.split_once(',').ok_or_else(|| syntax_error(self))?;
+        let number = number.trim().parse().map_err(|_| syntax_error(self))?;
+        Ok((number, element.trim()))
+    }
+}
+
+impl GameSystem for Cde {
+    fn name(&self) -> &'static str {
+        "cde"
+    }
+
+    fn syntax(&self) -> &'static str {
+        "cde(number_of_dice, element)"
+    }
+
+    fn description(&self) -> &'static str {
+        "Hong Kong : Chroniques de l'étrange, the element can be written in French"
+    }
+
+    fn expression(&self, params: &str) -> Result<String, String> {
+        Ok(format!("{}d10", self.params(params)?.0))
+    }
+
+    fn interpret(&self, params: &str, res: RollResult) -> Result<Outcome, String> {
+        let (_, element) = self.params(params)?;
+        caith::helpers::compute_cde(&res, element)
+            .map(|res| Outcome::text(res.to_string()))
+            .map_err(|_| syntax_error(self))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn cde_expression() {
+        assert_eq!(Ok("4d10".to_string()), Cde.expression("4, fire"));
+        assert!(Cde.expression("4").is_err());
+        assert!(Cde.expression("four, fire").is_err());
+    }
+}
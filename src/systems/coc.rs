@@ -0,0 +1,201 @@
+use std::collections::HashSet;
+
+use caith::{Critic, RollHistory, RollResult};
+
+use super::{syntax_error, GameSystem, Outcome};
+
+/// Call of Cthulhu 7th edition: d100 under the skill, with bonus or penalty dices
+pub struct Coc;
+
+struct CocParams {
+    skill: u64,
+    // positive for bonus dices, negative for penalty dices
+    extra: i64,
+}
+
+#[derive(Debug, PartialEq)]
+enum SuccessLevel {
+    Critical,
+    Extreme,
+    Hard,
+    Regular,
+    Failure,
+    Fumble,
+}
+
+impl SuccessLevel {
+    fn new(value: u64, skill: u64) -> Self {
+        let fumble = if skill < 50 {
+            value >= 96
+        } else {
+            value == 100
+        };
+        if value == 1 {
+            SuccessLevel::Critical
+        } else if fumble {
+            SuccessLevel::Fumble
+        } else if value <= skill / 5 {
+            SuccessLevel::Extreme
+        } else if value <= skill / 2 {
+            SuccessLevel::Hard
+        } else if value <= skill {
+            SuccessLevel::Regular
+        } else {
+            SuccessLevel::Failure
+        }
+    }
+
+    fn as_str(&self) -> &'static str {
+        match self {
+            SuccessLevel::Critical => "**Critical success** 🥳",
+            SuccessLevel::Extreme => "**Extreme success**",
+            SuccessLevel::Hard => "**Hard success**",
+            SuccessLevel::Regular => "**Regular success**",
+            SuccessLevel::Failure => "**Failure**",
+            SuccessLevel::Fumble => "**Fumble** 🤬",
+        }
+    }
+}
+
+impl Coc {
+    // `skill[, +bonus|-penalty]`
+    fn params(&self, params: &str) -> Result<CocParams, String> {
+        let (skill, extra) = match params.split_once(',') {
+            Some((skill, extra)) => (skill, extra.trim()),
+            None => (params, "0"),
+        };
+        let skill = skill.trim().parse().map_err(|_| syntax_error(self))?;
+        let extra = extra
+            .trim_start_matches('+')
+            .parse::<i64>()
+            .map_err(|_| syntax_error(self))?;
+        if extra.abs() > 2 {
+            return Err("At most 2 bonus or penalty dices".to_string());
+        }
+        Ok(CocParams { skill, extra })
+    }
+
+    // The tens dices and the units dice of the roll, as digits
+    fn digits(&self, res: &RollResult) -> Result<(Vec<u64>, u64), String> {
+        let mut groups = res
+            .as_single()
+            .ok_or_else(|| syntax_error(self))?
+            .get_history()
+            .iter()
+            .filter_map(|h| match h {
+                RollHistory::Roll(dices) => Some(dices.iter().map(|d| d.res % 10).collect()),
+                _ => None,
+            });
+        match (groups.next(), groups.next()) {
+            (Some(tens), Some(units)) => Ok((tens, units[0])),
+            _ => Err(syntax_error(self)),
+        }
+    }
+}
+
+// Value of the d100: `00` and `0` is 100. Bonus dices keep the best tens, penalty the worst.
+fn percentile(tens: &[u64], units: u64, extra: i64) -> u64 {
+    let values = tens.iter().map(|ten| match ten * 10 + units {
+        0 => 100,
+        value => value,
+    });
+    if extra >= 0 {
+        values.min().unwrap_or(100)
+    } else {
+        values.max().unwrap_or(100)
+    }
+}
+
+impl GameSystem for Coc {
+    fn name(&self) -> &'static str {
+        "coc"
+    }
+
+    fn syntax(&self) -> &'static str {
+        "coc(skill[, +bonus|-penalty])"
+    }
+
+    fn description(&self) -> &'static str {
+        "Call of Cthulhu: d100 under the skill, regular, hard or extreme success, with up to 2 \
+         bonus (`+1`) or penalty (`-1`) dices. Push the roll with `/reroll`"
+    }
+
+    fn expression(&self, params: &str) -> Result<String, String> {
+        let params = self.params(params)?;
+        // one d10 per tens dice, then the units dice
+        Ok(format!("{}d10 + 1d10", 1 + params.extra.abs()))
+    }
+
+    fn interpret(&self, params: &str, res: RollResult) -> Result<Outcome, String> {
+        let params = self.params(params)?;
+        let (tens, units) = self.digits(&res)?;
+        let value = percentile(&tens, units, params.extra);
+        let tens: Vec<String> = tens.iter().map(|ten| format!("{}0", ten)).collect();
+        let mut text = format!(
+            "`[{}] + [{}]` = **{}** / {} → {}",
+            tens.join(", "),
+            units,
+            value,
+            params.skill,
+            SuccessLevel::new(value, params.skill).as_str()
+        );
+        if let Some(reason) = res.get_reason() {
+            text.push_str(&format!(", Reason: `{}`", reason));
+        }
+        Ok(Outcome::text(text))
+    }
+
+    fn crit(&self, params: &str, res: &RollResult) -> HashSet<Critic> {
+        let mut crit = HashSet::new();
+        if let (Ok(params), Ok((tens, units))) = (self.params(params), self.digits(res)) {
+            match SuccessLevel::new(percentile(&tens, units, params.extra), params.skill) {
+                SuccessLevel::Critical => {
+                    crit.insert(Critic::Max);
+                }
+                SuccessLevel::Fumble => {
+                    crit.insert(Critic::Min);
+                }
+                _ => (),
+            }
+        }
+        crit
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::rng::rng_from_seed;
+
+    #[test]
+    fn success_levels() {
+        assert_eq!(SuccessLevel::Critical, SuccessLevel::new(1, 60));
+        assert_eq!(SuccessLevel::Extreme, SuccessLevel::new(12, 60));
+        assert_eq!(SuccessLevel::Hard, SuccessLevel::new(30, 60));
+        assert_eq!(SuccessLevel::Regular, SuccessLevel::new(60, 60));
+        assert_eq!(SuccessLevel::Failure, SuccessLevel::new(97, 60));
+        assert_eq!(SuccessLevel::Fumble, SuccessLevel::new(100, 60));
+        assert_eq!(SuccessLevel::Fumble, SuccessLevel::new(96, 40));
+    }
+
+    #[test]
+    fn bonus_and_penalty_dices() {
+        assert_eq!(34, percentile(&[3], 4, 0));
+        assert_eq!(100, percentile(&[0], 0, 0));
+        assert_eq!(4, percentile(&[3, 0], 4, 1));
+        assert_eq!(34, percentile(&[3, 0], 4, -1));
+        assert_eq!(100, percentile(&[3, 0], 0, -1));
+        assert_eq!(Ok("3d10 + 1d10".to_string()), Coc.expression("50, -2"));
+        assert!(Coc.expression("50, +3").is_err());
+    }
+
+    #[test]
+    fn coc_outcome() {
+        let roller = caith::Roller::new(&Coc.expression("45, +1").unwrap()).unwrap();
+        let res = roller.roll_with(&mut rng_from_seed(1)).unwrap();
+        assert_eq!(
+            "`[70, 20] + [6]` = **26** / 45 → **Regular success**",
+            Coc.interpret("45, +1", res).unwrap().text
+        );
+    }
+}
@@ -0,0 +1,99 @@
+use caith::{RollHistory, RollResult};
+
+use super::{syntax_error, GameSystem, Outcome};
+
+/// Forged in the Dark (Blades in the Dark): the highest of a pool of d6 tells the outcome
+pub struct Fitd;
+
+impl Fitd {
+    fn dices(&self, params: &str) -> Result<u32, String> {
+        params.trim().parse().map_err(|_| syntax_error(self))
+    }
+}
+
+// Outcome of the dices: with zero dice in the pool, two dices are rolled and the lowest is kept
+fn fitd_outcome(dices: &[u64], zero_dice: bool) -> &'static str {
+    let sixes = dices.iter().filter(|d| **d == 6).count();
+    let result = if zero_dice {
+        dices.iter().min()
+    } else {
+        dices.iter().max()
+    };
+    match result {
+        Some(6) if sixes >= 2 && !zero_dice => "**Critical success** 🥳",
+        Some(6) => "**Full success**",
+        Some(4) | Some(5) => "**Partial success**",
+        _ => "**Failure** 🤬",
+    }
+}
+
+impl GameSystem for Fitd {
+    fn name(&self) -> &'static str {
+        "fitd"
+    }
+
+    fn syntax(&self) -> &'static str {
+        "fitd(number_of_dice)"
+    }
+
+    fn description(&self) -> &'static str {
+        "Forged in the Dark games like Blades in the Dark: the highest dice gives a critical, full \
+         or partial success, or a failure. With 0 dice, 2 dices are rolled and the lowest is kept"
+    }
+
+    fn expression(&self, params: &str) -> Result<String, String> {
+        Ok(match self.dices(params)? {
+            0 => "2d6".to_string(),
+            n => format!("{}d6", n),
+        })
+    }
+
+    fn interpret(&self, params: &str, res: RollResult) -> Result<Outcome, String> {
+        let zero_dice = self.dices(params)? == 0;
+        let dices: Vec<u64> = res
+            .as_single()
+            .ok_or_else(|| syntax_error(self))?
+            .get_history()
+            .iter()
+            .filter_map(|h| match h {
+                RollHistory::Roll(dices) => Some(dices),
+                _ => None,
+            })
+            .flatten()
+            .map(|dice| dice.res)
+            .collect();
+        let values: Vec<String> = dices.iter().map(u64::to_string).collect();
+        let mut text = format!(
+            "`[{}]` → {}",
+            values.join(", "),
+            fitd_outcome(&dices, zero_dice)
+        );
+        if let Some(reason) = res.get_reason() {
+            text.push_str(&format!(", Reason: `{}`", reason));
+        }
+        Ok(Outcome::text(text))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn fitd_outcomes() {
+        assert_eq!("**Critical success** 🥳", fitd_outcome(&[6, 2, 6], false));
+        assert_eq!("**Full success**", fitd_outcome(&[6, 5, 1], false));
+        assert_eq!("**Partial success**", fitd_outcome(&[4, 1], false));
+        assert_eq!("**Failure** 🤬", fitd_outcome(&[3, 2, 1], false));
+        // zero dice: the lowest of two, never a critical
+        assert_eq!("**Full success**", fitd_outcome(&[6, 6], true));
+        assert_eq!("**Failure** 🤬", fitd_outcome(&[6, 2], true));
+    }
+
+    #[test]
+    fn fitd_expression() {
+        assert_eq!(Ok("3d6".to_string()), Fitd.expression("3"));
+        assert_eq!(Ok("2d6".to_string()), Fitd.expression("0"));
+        assert!(Fitd.expression("-1").is_err());
+    }
+}
@@ -0,0 +1,344 @@
+use std::collections::HashSet;
+
+use caith::{Critic, RollHistory, RollResult};
+
+use super::{syntax_error, GameSystem, Outcome};
+
+/// Genesys and Star Wars narrative dices: symbols instead of numbers, cancelling each other
+pub struct Genesys;
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum Symbol {
+    Success,
+    Failure,
+    Advantage,
+    Threat,
+    Triumph,
+    Despair,
+}
+
+use Symbol::*;
+
+impl Symbol {
+    fn emoji(self) -> &'static str {
+        match self {
+            Success => "✅",
+            Failure => "❌",
+            Advantage => "✨",
+            Threat => "⚠️",
+            Triumph => "🏆",
+            Despair => "💀",
+        }
+    }
+}
+
+/// A dice whose faces are symbols, rolled as a numeric dice with as many sides
+struct FaceDice {
+    letter: char,
+    emoji: &'static str,
+    faces: &'static [&'static [Symbol]],
+}
+
+impl FaceDice {
+    fn sides(&self) -> usize {
+        self.faces.len()
+    }
+}
+
+static DICES: &[FaceDice] = &[
+    // ability
+    FaceDice {
+        letter: 'g',
+        emoji: "🟩",
+        faces: &[
+            &[],
+            &[Success],
+            &[Success],
+            &[Success, Success],
+            &[Advantage],
+            &[Advantage],
+            &[Success, Advantage],
+            &[Advantage, Advantage],
+        ],
+    },
+    // proficiency
+    FaceDice {
+        letter: 'y',
+        emoji: "🟨",
+        faces: &[
+            &[],
+            &[Success],
+            &[Success],
+            &[Success, Success],
+            &[Success, Success],
+            &[Advantage],
+            &[Success, Advantage],
+            &[Success, Advantage],
+            &[Success, Advantage],
+            &[Advantage, Advantage],
+            &[Advantage, Advantage],
+            &[Triumph],
+        ],
+    },
+    // boost
+    FaceDice {
+        letter: 'b',
+        emoji: "🟦",
+        faces: &[
+            &[],
+            &[],
+            &[Success],
+            &[Success, Advantage],
+            &[Advantage, Advantage],
+            &[Advantage],
+        ],
+    },
+    // difficulty
+    FaceDice {
+        letter: 'p',
+        emoji: "🟪",
+        faces: &[
+            &[],
+            &[Failure],
+            &[Failure, Failure],
+            &[Threat],
+            &[Threat],
+            &[Threat],
+            &[Threat, Threat],
+            &[Failure, Threat],
+        ],
+    },
+    // challenge
+    FaceDice {
+        letter: 'r',
+        emoji: "🟥",
+        faces: &[
+            &[],
+            &[Failure],
+            &[Failure],
+            &[Failure, Failure],
+            &[Failure, Failure],
+            &[Threat],
+            &[Threat],
+            &[Failure, Threat],
+            &[Failure, Threat],
+            &[Threat, Threat],
+            &[Threat, Threat],
+            &[Despair],
+        ],
+    },
+    // setback
+    FaceDice {
+        letter: 'k',
+        emoji: "⬛",
+        faces: &[&[], &[], &[Failure], &[Failure], &[Threat], &[Threat]],
+    },
+];
+
+impl Genesys {
+    // `2g 1y 2p 1b`: number and letter of each dice, the number defaults to 1
+    fn dices(&self, params: &str) -> Result<Vec<(u32, &'static FaceDice)>, String> {
+        let dices = params
+            .split(|c: char| c.is_whitespace() || c == ',')
+            .filter(|term| !term.is_empty())
+            .map(|term| {
+                let last = term.chars().last().unwrap();
+                let letter = last.to_ascii_lowercase();
+                let count = match &term[..term.len() - last.len_utf8()] {
+                    "" => 1,
+                    count => count.parse().map_err(|_| syntax_error(self))?,
+                };
+                let dice = DICES
+                    .iter()
+                    .find(|dice| dice.letter == letter)
+                    .ok_or_else(|| {
+                        format!("Unknown dice `{}`, expected one of g, y, b, p, r, k", term)
+                    })?;
+                Ok((count, dice))
+            })
+            .filter(|dices| !matches!(dices, Ok((0, _))))
+            .collect::<Result<Vec<_>, String>>()?;
+        if dices.is_empty() {
+            Err(syntax_error(self))
+        } else {
+            Ok(dices)
+        }
+    }
+
+    // The faces rolled, with the dice they're on
+    fn faces(
+        &self,
+        params: &str,
+        res: &RollResult,
+    ) -> Result<Vec<(&'static FaceDice, &'static [Symbol])>, String> {
+        let rolls = res
+            .as_single()
+            .ok_or_else(|| syntax_error(self))?
+            .get_history()
+            .iter()
+            .filter_map(|h| match h {
+                RollHistory::Roll(values) => Some(values),
+                _ => None,
+            });
+        Ok(self
+            .dices(params)?
+            .into_iter()
+            .zip(rolls)
+            .flat_map(|((_, dice), values)| {
+                values
+                    .iter()
+                    .map(move |value| (dice, dice.faces[value.res as usize - 1]))
+            })
+            .collect())
+    }
+}
+
+/// Symbols left once successes cancel failures and advantages cancel threats.
+/// Triumphs count as successes and despairs as failures, but are never cancelled.
+fn cancel(symbols: &[Symbol]) -> Vec<(Symbol, usize)> {
+    let count = |symbol| symbols.iter().filter(|s| **s == symbol).count() as i64;
+    let successes = count(Success) + count(Triumph) - count(Failure) - count(Despair);
+    let advantages = count(Advantage) - count(Threat);
+    let mut left = Vec::new();
+    if successes > 0 {
+        left.push((Success, successes as usize));
+    } else if successes < 0 {
+        left.push((Failure, -successes as usize));
+    }
+    if advantages > 0 {
+        left.push((Advantage, advantages as usize));
+    } else if advantages < 0 {
+        left.push((Threat, -advantages as usize));
+    }
+    for symbol in [Triumph, Despair].iter() {
+        if count(*symbol) > 0 {
+            left.push((*symbol, count(*symbol) as usize));
+        }
+    }
+    left
+}
+
+fn genesys_outcome(faces: &[(&FaceDice, &[Symbol])]) -> String {
+    let rolled: Vec<String> = faces
+        .iter()
+        .map(|(dice, symbols)| {
+            let symbols: String = symbols.iter().map(|s| s.emoji()).collect();
+            format!(
+                "{}{}",
+                dice.emoji,
+                if symbols.is_empty() {
+                    "·".to_string()
+                } else {
+                    symbols
+                }
+            )
+        })
+        .collect();
+    let symbols: Vec<Symbol> = faces
+        .iter()
+        .flat_map(|(_, symbols)| symbols.iter().copied())
+        .collect();
+    let left = cancel(&symbols);
+    let outcome = if left.iter().any(|(symbol, _)| *symbol == Success) {
+        "**Success**"
+    } else {
+        "**Failure**"
+    };
+    let left: Vec<String> = left
+        .iter()
+        .map(|(symbol, count)| format!("{} {}", count, symbol.emoji()))
+        .collect();
+    let mut s = format!("{} → {}", rolled.join(" "), outcome);
+    if !left.is_empty() {
+        s.push_str(&format!(": {}", left.join(", ")));
+    }
+    s
+}
+
+impl GameSystem for Genesys {
+    fn name(&self) -> &'static str {
+        "gen"
+    }
+
+    fn syntax(&self) -> &'static str {
+        "gen(dices)"
+    }
+
+    fn description(&self) -> &'static str {
+        "Genesys and Star Wars narrative dices, ex: `gen(2g 1y 2p 1b)`. Ability (g), proficiency \
+         (y), boost (b), difficulty (p), challenge (r) and setback (k) dices, the symbols cancel \
+         each other"
+    }
+
+    fn expression(&self, params: &str) -> Result<String, String> {
+        let dices: Vec<String> = self
+            .dices(params)?
+            .iter()
+            .map(|(count, dice)| format!("{}d{}", count, dice.sides()))
+            .collect();
+        Ok(dices.join(" + "))
+    }
+
+    fn interpret(&self, params: &str, res: RollResult) -> Result<Outcome, String> {
+        let mut text = genesys_outcome(&self.faces(params, &res)?);
+        if let Some(reason) = res.get_reason() {
+            text.push_str(&format!(", Reason: `{}`", reason));
+        }
+        Ok(Outcome::text(text))
+    }
+
+    fn crit(&self, params: &str, res: &RollResult) -> HashSet<Critic> {
+        let mut crit = HashSet::new();
+        for (_, symbols) in self.faces(params, res).unwrap_or_default() {
+            if symbols.contains(&Triumph) {
+                crit.insert(Critic::Max);
+            }
+            if symbols.contains(&Despair) {
+                crit.insert(Critic::Min);
+            }
+        }
+        crit
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::rng::rng_from_seed;
+
+    #[test]
+    fn genesys_expression() {
+        assert_eq!(
+            Ok("2d8 + 1d12 + 2d8 + 1d6".to_string()),
+            Genesys.expression("2g 1y 2p 1b")
+        );
+        assert_eq!(Ok("1d12 + 1d6".to_string()), Genesys.expression("r, K"));
+        assert!(Genesys.expression("2x").is_err());
+        assert!(Genesys.expression("2é").is_err());
+        assert!(Genesys.expression("0g").is_err());
+    }
+
+    #[test]
+    fn symbols_cancel() {
+        assert_eq!(
+            vec![(Success, 1), (Threat, 2)],
+            cancel(&[Success, Success, Failure, Threat, Threat])
+        );
+        assert_eq!(
+            vec![(Failure, 1), (Triumph, 1), (Despair, 2)],
+            cancel(&[Triumph, Despair, Despair, Advantage, Threat])
+        );
+        assert!(cancel(&[Success, Failure]).is_empty());
+    }
+
+    #[test]
+    fn genesys_outcome_shows_the_faces() {
+        let params = "2g 1y 2p 1b";
+        let roller = caith::Roller::new(&Genesys.expression(params).unwrap()).unwrap();
+        let res = roller.roll_with(&mut rng_from_seed(1)).unwrap();
+        assert_eq!(
+            "🟩✨ 🟩✅ 🟨✨ 🟪❌⚠️ 🟪⚠️ 🟦✅ → **Success**: 1 ✅",
+            Genesys.interpret(params, res).unwrap().text
+        );
+    }
+}
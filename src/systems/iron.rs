@@ -0,0 +1,177 @@
+use caith::{RollHistory, RollResult};
+
+use super::{syntax_error, GameSystem, Outcome};
+
+/// Ironsworn action roll: a d6 plus the stat and adds against two d10 challenge dices
+pub struct Iron;
+
+/// Ironsworn progress roll: the progress score against two d10 challenge dices
+pub struct IronProgress;
+
+// An action score can't go over 10
+const MAX_SCORE: i64 = 10;
+
+// The values of the dices, by group
+fn dice_groups(res: &RollResult) -> Option<Vec<Vec<u64>>> {
+    Some(
+        res.as_single()?
+            .get_history()
+            .iter()
+            .filter_map(|h| match h {
+                RollHistory::Roll(dices) => Some(dices.iter().map(|d| d.res).collect()),
+                _ => None,
+            })
+            .collect(),
+    )
+}
+
+// The score beats the challenge dices strictly, a match is when both challenge dices are equal
+fn iron_outcome(score: i64, challenge: &[u64]) -> String {
+    let beaten = challenge.iter().filter(|d| score > **d as i64).count();
+    let mut s = format!(
+        "**{}** vs `{:?}` → {}",
+        score,
+        challenge,
+        match beaten {
+            2 => "**Strong hit** 🥳",
+            1 => "**Weak hit**",
+            _ => "**Miss** 🤬",
+        }
+    );
+    if challenge.len() == 2 && challenge[0] == challenge[1] {
+        s.push_str(", it's a match!");
+    }
+    s
+}
+
+fn with_reason(mut text: String, res: &RollResult) -> String {
+    if let Some(reason) = res.get_reason() {
+        text.push_str(&format!(", Reason: `{}`", reason));
+    }
+    text
+}
+
+impl Iron {
+    // `stat[, adds]`
+    fn params(&self, params: &str) -> Result<(i64, i64), String> {
+        let parse = |n: &str| {
+            n.trim()
+                .trim_start_matches('+')
+                .parse::<i64>()
+                .map_err(|_| syntax_error(self))
+        };
+        match params.split_once(',') {
+            Some((stat, adds)) => Ok((parse(stat)?, parse(adds)?)),
+            None => Ok((parse(params)?, 0)),
+        }
+    }
+}
+
+impl GameSystem for Iron {
+    fn name(&self) -> &'static str {
+        "iron"
+    }
+
+    fn syntax(&self) -> &'static str {
+        "iron(stat[, adds])"
+    }
+
+    fn description(&self) -> &'static str {
+        "Ironsworn action roll: d6 + stat + adds (10 at most) against two d10, strong hit when \
+         both are beaten, weak hit for one, miss otherwise. A match is when both d10 are equal"
+    }
+
+    fn expression(&self, params: &str) -> Result<String, String> {
+        self.params(params)?;
+        Ok("1d6 + 2d10".to_string())
+    }
+
+    fn interpret(&self, params: &str, res: RollResult) -> Result<Outcome, String> {
+        let (stat, adds) = self.params(params)?;
+        let groups = dice_groups(&res).ok_or_else(|| syntax_error(self))?;
+        let (action, challenge) = match groups.as_slice() {
+            [action, challenge] if action.len() == 1 => (action[0], challenge),
+            _ => return Err(syntax_error(self)),
+        };
+        let score = (action as i64 + stat + adds).min(MAX_SCORE);
+        let mut text = format!("`[{}]`", action);
+        for n in [stat, adds].iter().filter(|n| **n != 0) {
+            if *n > 0 {
+                text.push_str(&format!(" + {}", n));
+            } else {
+                text.push_str(&format!(" - {}", -n));
+            }
+        }
+        text.push_str(&format!(" = {}", iron_outcome(score, challenge)));
+        Ok(Outcome::text(with_reason(text, &res)))
+    }
+}
+
+impl IronProgress {
+    fn score(&self, params: &str) -> Result<i64, String> {
+        match params.trim().parse() {
+            Ok(score) if (0..=MAX_SCORE).contains(&score) => Ok(score),
+            _ => Err(syntax_error(self)),
+        }
+    }
+}
+
+impl GameSystem for IronProgress {
+    fn name(&self) -> &'static str {
+        "ironp"
+    }
+
+    fn syntax(&self) -> &'static str {
+        "ironp(progress)"
+    }
+
+    fn description(&self) -> &'static str {
+        "Ironsworn progress roll: the progress score (0 to 10) against two d10, see `/progress` \
+         to keep the tracks"
+    }
+
+    fn expression(&self, params: &str) -> Result<String, String> {
+        self.score(params)?;
+        Ok("2d10".to_string())
+    }
+
+    fn interpret(&self, params: &str, res: RollResult) -> Result<Outcome, String> {
+        let score = self.score(params)?;
+        let groups = dice_groups(&res).ok_or_else(|| syntax_error(self))?;
+        let challenge = groups.first().ok_or_else(|| syntax_error(self))?;
+        let text = format!("Progress {}", iron_outcome(score, challenge));
+        Ok(Outcome::text(with_reason(text, &res)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::rng::rng_from_seed;
+
+    #[test]
+    fn iron_outcomes() {
+        assert_eq!(
+            "**7** vs `[3, 6]` → **Strong hit** 🥳",
+            iron_outcome(7, &[3, 6])
+        );
+        assert_eq!("**7** vs `[3, 7]` → **Weak hit**", iron_outcome(7, &[3, 7]));
+        assert_eq!(
+            "**4** vs `[9, 9]` → **Miss** 🤬, it's a match!",
+            iron_outcome(4, &[9, 9])
+        );
+        assert!(Iron.expression("2, +1").is_ok());
+        assert!(Iron.expression("edge").is_err());
+        assert!(IronProgress.expression("11").is_err());
+    }
+
+    #[test]
+    fn iron_action_roll() {
+        let roller = caith::Roller::new(&Iron.expression("3, 1").unwrap()).unwrap();
+        let res = roller.roll_with(&mut rng_from_seed(1)).unwrap();
+        assert_eq!(
+            "`[4]` + 3 + 1 = **8** vs `[6, 2]` → **Strong hit** 🥳",
+            Iron.interpret("3, 1", res).unwrap().text
+        );
+    }
+}
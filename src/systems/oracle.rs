@@ -0,0 +1,2383 @@
+use caith::{RollHistory, RollResult};
+
+use super::{syntax_error, GameSystem, Outcome};
+
+/// Ironsworn oracles: random prompts from a table, or a yes/no answer with its odds
+pub struct Oracle;
+
+// Each entry has the same chance, rolled with a dice of as many sides. The tables of the rulebook
+// give a range of the d100 to some rows, they are repeated to have an entry for each value.
+struct Table {
+    name: &'static str,
+    entries: &'static [&'static str],
+}
+
+// Oracles of the Ironsworn rulebook by Shawn Tomkin, under CC BY 4.0
+static TABLES: &[Table] = &[
+    Table {
+        name: "action",
+        entries: &[
+            "Scheme",
+            "Clash",
+            "Weaken",
+            "Initiate",
+            "Create",
+            "Swear",
+            "Avenge",
+            "Guard",
+            "Defeat",
+            "Control",
+            "Break",
+            "Risk",
+            "Surrender",
+            "Inspect",
+            "Raid",
+            "Evade",
+            "Assault",
+            "Deflect",
+            "Threaten",
+            "Attack",
+            "Leave",
+            "Preserve",
+            "Manipulate",
+            "Remove",
+            "Eliminate",
+            "Withdraw",
+            "Abandon",
+            "Investigate",
+            "Hold",
+            "Focus",
+            "Uncover",
+            "Breach",
+            "Aid",
+            "Uphold",
+            "Falter",
+            "Suppress",
+            "Hunt",
+            "Share",
+            "Destroy",
+            "Avoid",
+            "Reject",
+            "Demand",
+            "Explore",
+            "Bolster",
+            "Seize",
+            "Mourn",
+            "Reveal",
+            "Gather",
+            "Defy",
+            "Transform",
+            "Persevere",
+            "Serve",
+            "Begin",
+            "Move",
+            "Coordinate",
+            "Resist",
+            "Await",
+            "Impress",
+            "Take",
+            "Oppose",
+            "Capture",
+            "Overwhelm",
+            "Challenge",
+            "Acquire",
+            "Protect",
+            "Finish",
+            "Strengthen",
+            "Restore",
+            "Advance",
+            "Command",
+            "Refuse",
+            "Find",
+            "Deliver",
+            "Hide",
+            "Fortify",
+            "Betray",
+            "Secure",
+            "Arrive",
+            "Affect",
+            "Change",
+            "Defend",
+            "Debate",
+            "Support",
+            "Follow",
+            "Construct",
+            "Locate",
+            "Endure",
+            "Release",
+            "Lose",
+            "Reduce",
+            "Escalate",
+            "Distract",
+            "Journey",
+            "Escort",
+            "Learn",
+            "Communicate",
+            "Depart",
+            "Search",
+            "Charge",
+            "Summon",
+        ],
+    },
+    Table {
+        name: "theme",
+        entries: &[
+            "Risk",
+            "Ability",
+            "Price",
+            "Ally",
+            "Battle",
+            "Safety",
+            "Survival",
+            "Weapon",
+            "Wound",
+            "Shelter",
+            "Leader",
+            "Fear",
+            "Time",
+            "Duty",
+            "Secret",
+            "Innocence",
+            "Renown",
+            "Direction",
+            "Death",
+            "Honor",
+            "Labor",
+            "Solution",
+            "Tool",
+            "Balance",
+            "Love",
+            "Barrier",
+            "Creation",
+            "Decay",
+            "Trade",
+            "Bond",
+            "Hope",
+            "Superstition",
+            "Peace",
+            "Deception",
+            "History",
+            "World",
+            "Vow",
+            "Protection",
+            "Nature",
+            "Opinion",
+            "Burden",
+            "Vengeance",
+            "Opportunity",
+            "Faction",
+            "Danger",
+            "Corruption",
+            "Freedom",
+            "Debt",
+            "Hate",
+            "Possession",
+            "Stranger",
+            "Passage",
+            "Land",
+            "Creature",
+            "Disease",
+            "Advantage",
+            "Blood",
+            "Language",
+            "Rumor",
+            "Weakness",
+            "Greed",
+            "Family",
+            "Resource",
+            "Structure",
+            "Dream",
+            "Community",
+            "War",
+            "Portent",
+            "Prize",
+            "Destiny",
+            "Momentum",
+            "Power",
+            "Memory",
+            "Ruin",
+            "Mysticism",
+            "Rival",
+            "Problem",
+            "Idea",
+            "Revenge",
+            "Health",
+            "Fellowship",
+            "Enemy",
+            "Religion",
+            "Spirit",
+            "Fame",
+            "Desolation",
+            "Strength",
+            "Knowledge",
+            "Truth",
+            "Quest",
+            "Pride",
+            "Loss",
+            "Law",
+            "Path",
+            "Warning",
+            "Relationship",
+            "Wealth",
+            "Home",
+            "Strategy",
+            "Supply",
+        ],
+    },
+    Table {
+        name: "pay the price",
+        entries: &[
+            "Roll again and apply that result but make it worse. If you roll this result yet again, think of something dreadful that changes the course of your quest (Ask the Oracle if unsure) and make it happen",
+            "Roll again and apply that result but make it worse. If you roll this result yet again, think of something dreadful that changes the course of your quest (Ask the Oracle if unsure) and make it happen",
+            "A person or community you trusted loses faith in you, or acts against you",
+            "A person or community you trusted loses faith in you, or acts against you",
+            "A person or community you trusted loses faith in you, or acts against you",
+            "A person or community you care about is exposed to danger",
+            "A person or community you care about is exposed to danger",
+            "A person or community you care about is exposed to danger",
+            "A person or community you care about is exposed to danger",
+            "You are separated from something or someone",
+            "You are separated from something or someone",
+            "You are separated from something or someone",
+            "You are separated from something or someone",
+            "You are separated from something or someone",
+            "You are separated from something or someone",
+            "You are separated from something or someone",
+            "Your action has an unintended effect",
+            "Your action has an unintended effect",
+            "Your action has an unintended effect",
+            "Your action has an unintended effect",
+            "Your action has an unintended effect",
+            "Your action has an unintended effect",
+            "Your action has an unintended effect",
+            "Something of value is lost or destroyed",
+            "Something of value is lost or destroyed",
+            "Something of value is lost or destroyed",
+            "Something of value is lost or destroyed",
+            "Something of value is lost or destroyed",
+            "Something of value is lost or destroyed",
+            "Something of value is lost or destroyed",
+            "Something of value is lost or destroyed",
+            "Something of value is lost or destroyed",
+            "The current situation worsens",
+            "The current situation worsens",
+            "The current situation worsens",
+            "The current situation worsens",
+            "The current situation worsens",
+            "The current situation worsens",
+            "The current situation worsens",
+            "The current situation worsens",
+            "The current situation worsens",
+            "A new danger or foe is revealed",
+            "A new danger or foe is revealed",
+            "A new danger or foe is revealed",
+            "A new danger or foe is revealed",
+            "A new danger or foe is revealed",
+            "A new danger or foe is revealed",
+            "A new danger or foe is revealed",
+            "A new danger or foe is revealed",
+            "A new danger or foe is revealed",
+            "It causes a delay or puts you at a disadvantage",
+            "It causes a delay or puts you at a disadvantage",
+            "It causes a delay or puts you at a disadvantage",
+            "It causes a delay or puts you at a disadvantage",
+            "It causes a delay or puts you at a disadvantage",
+            "It causes a delay or puts you at a disadvantage",
+            "It causes a delay or puts you at a disadvantage",
+            "It causes a delay or puts you at a disadvantage",
+            "It causes a delay or puts you at a disadvantage",
+            "It is harmful",
+            "It is harmful",
+            "It is harmful",
+            "It is harmful",
+            "It is harmful",
+            "It is harmful",
+            "It is harmful",
+            "It is harmful",
+            "It is harmful",
+            "It is stressful",
+            "It is stressful",
+            "It is stressful",
+            "It is stressful",
+            "It is stressful",
+            "It is stressful",
+            "It is stressful",
+            "It is stressful",
+            "A surprising development complicates your quest",
+            "A surprising development complicates your quest",
+            "A surprising development complicates your quest",
+            "A surprising development complicates your quest",
+            "A surprising development complicates your quest",
+            "A surprising development complicates your quest",
+            "A surprising development complicates your quest",
+            "A surprising development complicates your quest",
+            "A surprising development complicates your quest",
+            "It wastes resources",
+            "It wastes resources",
+            "It wastes resources",
+            "It wastes resources",
+            "It wastes resources",
+            "It forces you to act against your best intentions",
+            "It forces you to act against your best intentions",
+            "It forces you to act against your best intentions",
+            "It forces you to act against your best intentions",
+            "A friend, companion, or ally is put in harm's way (or you are, if alone)",
+            "A friend, companion, or ally is put in harm's way (or you are, if alone)",
+            "A friend, companion, or ally is put in harm's way (or you are, if alone)",
+            "A friend, companion, or ally is put in harm's way (or you are, if alone)",
+            "Roll twice more on this table. Both results occur. If they are the same result, make it worse",
+            "Roll twice more on this table. Both results occur. If they are the same result, make it worse",
+        ],
+    },
+    Table {
+        name: "region",
+        entries: &[
+            "Barrier Islands",
+            "Barrier Islands",
+            "Barrier Islands",
+            "Barrier Islands",
+            "Barrier Islands",
+            "Barrier Islands",
+            "Barrier Islands",
+            "Barrier Islands",
+            "Barrier Islands",
+            "Barrier Islands",
+            "Barrier Islands",
+            "Barrier Islands",
+            "Ragged Coast",
+            "Ragged Coast",
+            "Ragged Coast",
+            "Ragged Coast",
+            "Ragged Coast",
+            "Ragged Coast",
+            "Ragged Coast",
+            "Ragged Coast",
+            "Ragged Coast",
+            "Ragged Coast",
+            "Ragged Coast",
+            "Ragged Coast",
+            "Deep Wilds",
+            "Deep Wilds",
+            "Deep Wilds",
+            "Deep Wilds",
+            "Deep Wilds",
+            "Deep Wilds",
+            "Deep Wilds",
+            "Deep Wilds",
+            "Deep Wilds",
+            "Deep Wilds",
+            "Flooded Lands",
+            "Flooded Lands",
+            "Flooded Lands",
+            "Flooded Lands",
+            "Flooded Lands",
+            "Flooded Lands",
+            "Flooded Lands",
+            "Flooded Lands",
+            "Flooded Lands",
+            "Flooded Lands",
+            "Flooded Lands",
+            "Flooded Lands",
+            "Havens",
+            "Havens",
+            "Havens",
+            "Havens",
+            "Havens",
+            "Havens",
+            "Havens",
+            "Havens",
+            "Havens",
+            "Havens",
+            "Havens",
+            "Havens",
+            "Havens",
+            "Havens",
+            "Hinterlands",
+            "Hinterlands",
+            "Hinterlands",
+            "Hinterlands",
+            "Hinterlands",
+            "Hinterlands",
+            "Hinterlands",
+            "Hinterlands",
+            "Hinterlands",
+            "Hinterlands",
+            "Hinterlands",
+            "Hinterlands",
+            "Tempest Hills",
+            "Tempest Hills",
+            "Tempest Hills",
+            "Tempest Hills",
+            "Tempest Hills",
+            "Tempest Hills",
+            "Tempest Hills",
+            "Tempest Hills",
+            "Tempest Hills",
+            "Tempest Hills",
+            "Tempest Hills",
+            "Tempest Hills",
+            "Veiled Mountains",
+            "Veiled Mountains",
+            "Veiled Mountains",
+            "Veiled Mountains",
+            "Veiled Mountains",
+            "Veiled Mountains",
+            "Veiled Mountains",
+            "Veiled Mountains",
+            "Veiled Mountains",
+            "Veiled Mountains",
+            "Shattered Wastes",
+            "Shattered Wastes",
+            "Shattered Wastes",
+            "Shattered Wastes",
+            "Shattered Wastes",
+            "Elsewhere",
+        ],
+    },
+    Table {
+        name: "location",
+        entries: &[
+            "Hideout",
+            "Hideout",
+            "Hideout",
+            "Hideout",
+            "Ruin",
+            "Ruin",
+            "Ruin",
+            "Ruin",
+            "Mine",
+            "Mine",
+            "Waste",
+            "Waste",
+            "Mystical Site",
+            "Mystical Site",
+            "Path",
+            "Path",
+            "Outpost",
+            "Outpost",
+            "Wall",
+            "Wall",
+            "Battlefield",
+            "Battlefield",
+            "Hovel",
+            "Hovel",
+            "Spring",
+            "Spring",
+            "Lair",
+            "Lair",
+            "Fort",
+            "Fort",
+            "Bridge",
+            "Bridge",
+            "Camp",
+            "Camp",
+            "Cairn/Grave",
+            "Cairn/Grave",
+            "Caravan",
+            "Caravan",
+            "Waterfall",
+            "Waterfall",
+            "Cave",
+            "Cave",
+            "Swamp",
+            "Swamp",
+            "Fen",
+            "Fen",
+            "Ravine",
+            "Ravine",
+            "Road",
+            "Road",
+            "Tree",
+            "Tree",
+            "Pond",
+            "Pond",
+            "Fields",
+            "Fields",
+            "Marsh",
+            "Marsh",
+            "Steading",
+            "Steading",
+            "Rapids",
+            "Rapids",
+            "Pass",
+            "Pass",
+            "Trail",
+            "Trail",
+            "Glade",
+            "Glade",
+            "Plain",
+            "Plain",
+            "Ridge",
+            "Ridge",
+            "Cliff",
+            "Cliff",
+            "Grove",
+            "Grove",
+            "Village",
+            "Village",
+            "Moor",
+            "Moor",
+            "Thicket",
+            "Thicket",
+            "River Ford",
+            "River Ford",
+            "Valley",
+            "Valley",
+            "Bay/Fjord",
+            "Bay/Fjord",
+            "Foothills",
+            "Foothills",
+            "Lake",
+            "Lake",
+            "River",
+            "Forest",
+            "Coast",
+            "Glacier",
+            "Isle",
+            "Mountain",
+            "Woods",
+            "Anomaly",
+        ],
+    },
+    Table {
+        name: "coastal waters",
+        entries: &[
+            "Fleet",
+            "Fleet",
+            "Fleet",
+            "Fleet",
+            "Fleet",
+            "Fleet",
+            "Fleet",
+            "Fleet",
+            "Fleet",
+            "Fleet",
+            "Sargassum",
+            "Sargassum",
+            "Sargassum",
+            "Sargassum",
+            "Sargassum",
+            "Sargassum",
+            "Sargassum",
+            "Sargassum",
+            "Sargassum",
+            "Sargassum",
+            "Flotsam",
+            "Flotsam",
+            "Flotsam",
+            "Flotsam",
+            "Flotsam",
+            "Flotsam",
+            "Flotsam",
+            "Flotsam",
+            "Flotsam",
+            "Flotsam",
+            "Mystical Site",
+            "Mystical Site",
+            "Mystical Site",
+            "Mystical Site",
+            "Mystical Site",
+            "Mystical Site",
+            "Mystical Site",
+            "Mystical Site",
+            "Mystical Site",
+            "Mystical Site",
+            "Lair",
+            "Lair",
+            "Lair",
+            "Lair",
+            "Lair",
+            "Lair",
+            "Lair",
+            "Lair",
+            "Lair",
+            "Lair",
+            "Wreck",
+            "Wreck",
+            "Wreck",
+            "Wreck",
+            "Wreck",
+            "Wreck",
+            "Wreck",
+            "Wreck",
+            "Wreck",
+            "Wreck",
+            "Harbor",
+            "Harbor",
+            "Harbor",
+            "Harbor",
+            "Harbor",
+            "Harbor",
+            "Harbor",
+            "Harbor",
+            "Harbor",
+            "Harbor",
+            "Ship",
+            "Ship",
+            "Ship",
+            "Ship",
+            "Ship",
+            "Ship",
+            "Ship",
+            "Ship",
+            "Ship",
+            "Ship",
+            "Rocks",
+            "Rocks",
+            "Rocks",
+            "Rocks",
+            "Rocks",
+            "Fjord",
+            "Fjord",
+            "Fjord",
+            "Fjord",
+            "Fjord",
+            "Estuary",
+            "Estuary",
+            "Estuary",
+            "Estuary",
+            "Estuary",
+            "Cove",
+            "Cove",
+            "Bay",
+            "Bay",
+            "Ice Floe",
+        ],
+    },
+    Table {
+        name: "location descriptor",
+        entries: &[
+            "High",
+            "High",
+            "Remote",
+            "Remote",
+            "Lonely",
+            "Lonely",
+            "Somber",
+            "Somber",
+            "Overgrown",
+            "Overgrown",
+            "Flooded",
+            "Flooded",
+            "Low",
+            "Low",
+            "Rocky",
+            "Rocky",
+            "Sheltered",
+            "Sheltered",
+            "Bright",
+            "Bright",
+            "Dark",
+            "Dark",
+            "Shadowy",
+            "Shadowy",
+            "Beautiful",
+            "Beautiful",
+            "Lush",
+            "Lush",
+            "Icy",
+            "Icy",
+            "Desolate",
+            "Desolate",
+            "Barren",
+            "Barren",
+            "Dense",
+            "Dense",
+            "Dry",
+            "Dry",
+            "Verdant",
+            "Verdant",
+            "Hidden",
+            "Hidden",
+            "Protected",
+            "Protected",
+            "Abandoned",
+            "Abandoned",
+            "Wild",
+            "Wild",
+            "Blighted",
+            "Blighted",
+            "Cursed",
+            "Cursed",
+            "Mystical",
+            "Mystical",
+            "Hallowed",
+            "Hallowed",
+            "Ancient",
+            "Ancient",
+            "Haunted",
+            "Haunted",
+            "Infested",
+            "Infested",
+            "Foreboding",
+            "Foreboding",
+            "Dangerous",
+            "Dangerous",
+            "Ruined",
+            "Ruined",
+            "Frozen",
+            "Frozen",
+            "Corrupted",
+            "Corrupted",
+            "Peaceful",
+            "Peaceful",
+            "Sacred",
+            "Sacred",
+            "Windswept",
+            "Windswept",
+            "Misty",
+            "Misty",
+            "Vast",
+            "Vast",
+            "Fortified",
+            "Fortified",
+            "Cold",
+            "Cold",
+            "Grim",
+            "Grim",
+            "Muddy",
+            "Muddy",
+            "Treacherous",
+            "Treacherous",
+            "Silent",
+            "Silent",
+            "Crumbling",
+            "Crumbling",
+            "Fiery",
+            "Fiery",
+            "Roll twice",
+            "Roll twice",
+        ],
+    },
+    Table {
+        name: "settlement name",
+        entries: &[
+            "A feature of the landscape. Envision what it is. What makes it unusual or distinctive? (Highmount, Brackwater, Frostwood, Redcrest, Grimtree, Stoneford, Deepwater, Whiteridge, Thornhill, Silverstream)",
+            "A feature of the landscape. Envision what it is. What makes it unusual or distinctive? (Highmount, Brackwater, Frostwood, Redcrest, Grimtree, Stoneford, Deepwater, Whiteridge, Thornhill, Silverstream)",
+            "A feature of the landscape. Envision what it is. What makes it unusual or distinctive? (Highmount, Brackwater, Frostwood, Redcrest, Grimtree, Stoneford, Deepwater, Whiteridge, Thornhill, Silverstream)",
+            "A feature of the landscape. Envision what it is. What makes it unusual or distinctive? (Highmount, Brackwater, Frostwood, Redcrest, Grimtree, Stoneford, Deepwater, Whiteridge, Thornhill, Silverstream)",
+            "A feature of the landscape. Envision what it is. What makes it unusual or distinctive? (Highmount, Brackwater, Frostwood, Redcrest, Grimtree, Stoneford, Deepwater, Whiteridge, Thornhill, Silverstream)",
+            "A feature of the landscape. Envision what it is. What makes it unusual or distinctive? (Highmount, Brackwater, Frostwood, Redcrest, Grimtree, Stoneford, Deepwater, Whiteridge, Thornhill, Silverstream)",
+            "A feature of the landscape. Envision what it is. What makes it unusual or distinctive? (Highmount, Brackwater, Frostwood, Redcrest, Grimtree, Stoneford, Deepwater, Whiteridge, Thornhill, Silverstream)",
+            "A feature of the landscape. Envision what it is. What makes it unusual or distinctive? (Highmount, Brackwater, Frostwood, Redcrest, Grimtree, Stoneford, Deepwater, Whiteridge, Thornhill, Silverstream)",
+            "A feature of the landscape. Envision what it is. What makes it unusual or distinctive? (Highmount, Brackwater, Frostwood, Redcrest, Grimtree, Stoneford, Deepwater, Whiteridge, Thornhill, Silverstream)",
+            "A feature of the landscape. Envision what it is. What makes it unusual or distinctive? (Highmount, Brackwater, Frostwood, Redcrest, Grimtree, Stoneford, Deepwater, Whiteridge, Thornhill, Silverstream)",
+            "A feature of the landscape. Envision what it is. What makes it unusual or distinctive? (Highmount, Brackwater, Frostwood, Redcrest, Grimtree, Stoneford, Deepwater, Whiteridge, Thornhill, Silverstream)",
+            "A feature of the landscape. Envision what it is. What makes it unusual or distinctive? (Highmount, Brackwater, Frostwood, Redcrest, Grimtree, Stoneford, Deepwater, Whiteridge, Thornhill, Silverstream)",
+            "A feature of the landscape. Envision what it is. What makes it unusual or distinctive? (Highmount, Brackwater, Frostwood, Redcrest, Grimtree, Stoneford, Deepwater, Whiteridge, Thornhill, Silverstream)",
+            "A feature of the landscape. Envision what it is. What makes it unusual or distinctive? (Highmount, Brackwater, Frostwood, Redcrest, Grimtree, Stoneford, Deepwater, Whiteridge, Thornhill, Silverstream)",
+            "A feature of the landscape. Envision what it is. What makes it unusual or distinctive? (Highmount, Brackwater, Frostwood, Redcrest, Grimtree, Stoneford, Deepwater, Whiteridge, Thornhill, Silverstream)",
+            "A manmade edifice. What is it? Why is it important to this settlement's history? (Whitebridge, Lonefort, Highcross, Timberwall, Stonetower, Thornhall, Cinderhome, Fallowfield)",
+            "A manmade edifice. What is it? Why is it important to this settlement's history? (Whitebridge, Lonefort, Highcross, Timberwall, Stonetower, Thornhall, Cinderhome, Fallowfield)",
+            "A manmade edifice. What is it? Why is it important to this settlement's history? (Whitebridge, Lonefort, Highcross, Timberwall, Stonetower, Thornhall, Cinderhome, Fallowfield)",
+            "A manmade edifice. What is it? Why is it important to this settlement's history? (Whitebridge, Lonefort, Highcross, Timberwall, Stonetower, Thornhall, Cinderhome, Fallowfield)",
+            "A manmade edifice. What is it? Why is it important to this settlement's history? (Whitebridge, Lonefort, Highcross, Timberwall, Stonetower, Thornhall, Cinderhome, Fallowfield)",
+            "A manmade edifice. What is it? Why is it important to this settlement's history? (Whitebridge, Lonefort, Highcross, Timberwall, Stonetower, Thornhall, Cinderhome, Fallowfield)",
+            "A manmade edifice. What is it? Why is it important to this settlement's history? (Whitebridge, Lonefort, Highcross, Timberwall, Stonetower, Thornhall, Cinderhome, Fallowfield)",
+            "A manmade edifice. What is it? Why is it important to this settlement's history? (Whitebridge, Lonefort, Highcross, Timberwall, Stonetower, Thornhall, Cinderhome, Fallowfield)",
+            "A manmade edifice. What is it? Why is it important to this settlement's history? (Whitebridge, Lonefort, Highcross, Timberwall, Stonetower, Thornhall, Cinderhome, Fallowfield)",
+            "A manmade edifice. What is it? Why is it important to this settlement's history? (Whitebridge, Lonefort, Highcross, Timberwall, Stonetower, Thornhall, Cinderhome, Fallowfield)",
+            "A manmade edifice. What is it? Why is it important to this settlement's history? (Whitebridge, Lonefort, Highcross, Timberwall, Stonetower, Thornhall, Cinderhome, Fallowfield)",
+            "A manmade edifice. What is it? Why is it important to this settlement's history? (Whitebridge, Lonefort, Highcross, Timberwall, Stonetower, Thornhall, Cinderhome, Fallowfield)",
+            "A manmade edifice. What is it? Why is it important to this settlement's history? (Whitebridge, Lonefort, Highcross, Timberwall, Stonetower, Thornhall, Cinderhome, Fallowfield)",
+            "A manmade edifice. What is it? Why is it important to this settlement's history? (Whitebridge, Lonefort, Highcross, Timberwall, Stonetower, Thornhall, Cinderhome, Fallowfield)",
+            "A manmade edifice. What is it? Why is it important to this settlement's history? (Whitebridge, Lonefort, Highcross, Timberwall, Stonetower, Thornhall, Cinderhome, Fallowfield)",
+            "A creature. Why has this creature become associated with the settlement? (Ravencliff, Bearmark, Wolfcrag, Eaglespire, Wyvern's Rest, Two Foxes, Elderwatch, Dragonshadow)",
+            "A creature. Why has this creature become associated with the settlement? (Ravencliff, Bearmark, Wolfcrag, Eaglespire, Wyvern's Rest, Two Foxes, Elderwatch, Dragonshadow)",
+            "A creature. Why has this creature become associated with the settlement? (Ravencliff, Bearmark, Wolfcrag, Eaglespire, Wyvern's Rest, Two Foxes, Elderwatch, Dragonshadow)",
+            "A creature. Why has this creature become associated with the settlement? (Ravencliff, Bearmark, Wolfcrag, Eaglespire, Wyvern's Rest, Two Foxes, Elderwatch, Dragonshadow)",
+            "A creature. Why has this creature become associated with the settlement? (Ravencliff, Bearmark, Wolfcrag, Eaglespire, Wyvern's Rest, Two Foxes, Elderwatch, Dragonshadow)",
+            "A creature. Why has this creature become associated with the settlement? (Ravencliff, Bearmark, Wolfcrag, Eaglespire, Wyvern's Rest, Two Foxes, Elderwatch, Dragonshadow)",
+            "A creature. Why has this creature become associated with the settlement? (Ravencliff, Bearmark, Wolfcrag, Eaglespire, Wyvern's Rest, Two Foxes, Elderwatch, Dragonshadow)",
+            "A creature. Why has this creature become associated with the settlement? (Ravencliff, Bearmark, Wolfcrag, Eaglespire, Wyvern's Rest, Two Foxes, Elderwatch, Dragonshadow)",
+            "A creature. Why has this creature become associated with the settlement? (Ravencliff, Bearmark, Wolfcrag, Eaglespire, Wyvern's Rest, Two Foxes, Elderwatch, Dragonshadow)",
+            "A creature. Why has this creature become associated with the settlement? (Ravencliff, Bearmark, Wolfcrag, Eaglespire, Wyvern's Rest, Two Foxes, Elderwatch, Dragonshadow)",
+            "A creature. Why has this creature become associated with the settlement? (Ravencliff, Bearmark, Wolfcrag, Eaglespire, Wyvern's Rest, Two Foxes, Elderwatch, Dragonshadow)",
+            "A creature. Why has this creature become associated with the settlement? (Ravencliff, Bearmark, Wolfcrag, Eaglespire, Wyvern's Rest, Two Foxes, Elderwatch, Dragonshadow)",
+            "A creature. Why has this creature become associated with the settlement? (Ravencliff, Bearmark, Wolfcrag, Eaglespire, Wyvern's Rest, Two Foxes, Elderwatch, Dragonshadow)",
+            "A creature. Why has this creature become associated with the settlement? (Ravencliff, Bearmark, Wolfcrag, Eaglespire, Wyvern's Rest, Two Foxes, Elderwatch, Dragonshadow)",
+            "A creature. Why has this creature become associated with the settlement? (Ravencliff, Bearmark, Wolfcrag, Eaglespire, Wyvern's Rest, Two Foxes, Elderwatch, Dragonshadow)",
+            "A historical event. What happened here? What place or object commemorates it? (Swordbreak, Fool's Fall, Firstmeet, Brokenhelm, Mournhaunt, Olgar's Stand, Lostwater, Rojirra's Lament)",
+            "A historical event. What happened here? What place or object commemorates it? (Swordbreak, Fool's Fall, Firstmeet, Brokenhelm, Mournhaunt, Olgar's Stand, Lostwater, Rojirra's Lament)",
+            "A historical event. What happened here? What place or object commemorates it? (Swordbreak, Fool's Fall, Firstmeet, Brokenhelm, Mournhaunt, Olgar's Stand, Lostwater, Rojirra's Lament)",
+            "A historical event. What happened here? What place or object commemorates it? (Swordbreak, Fool's Fall, Firstmeet, Brokenhelm, Mournhaunt, Olgar's Stand, Lostwater, Rojirra's Lament)",
+            "A historical event. What happened here? What place or object commemorates it? (Swordbreak, Fool's Fall, Firstmeet, Brokenhelm, Mournhaunt, Olgar's Stand, Lostwater, Rojirra's Lament)",
+            "A historical event. What happened here? What place or object commemorates it? (Swordbreak, Fool's Fall, Firstmeet, Brokenhelm, Mournhaunt, Olgar's Stand, Lostwater, Rojirra's Lament)",
+            "A historical event. What happened here? What place or object commemorates it? (Swordbreak, Fool's Fall, Firstmeet, Brokenhelm, Mournhaunt, Olgar's Stand, Lostwater, Rojirra's Lament)",
+            "A historical event. What happened here? What place or object commemorates it? (Swordbreak, Fool's Fall, Firstmeet, Brokenhelm, Mournhaunt, Olgar's Stand, Lostwater, Rojirra's Lament)",
+            "A historical event. What happened here? What place or object commemorates it? (Swordbreak, Fool's Fall, Firstmeet, Brokenhelm, Mournhaunt, Olgar's Stand, Lostwater, Rojirra's Lament)",
+            "A historical event. What happened here? What place or object commemorates it? (Swordbreak, Fool's Fall, Firstmeet, Brokenhelm, Mournhaunt, Olgar's Stand, Lostwater, Rojirra's Lament)",
+            "A historical event. What happened here? What place or object commemorates it? (Swordbreak, Fool's Fall, Firstmeet, Brokenhelm, Mournhaunt, Olgar's Stand, Lostwater, Rojirra's Lament)",
+            "A historical event. What happened here? What place or object commemorates it? (Swordbreak, Fool's Fall, Firstmeet, Brokenhelm, Mournhaunt, Olgar's Stand, Lostwater, Rojirra's Lament)",
+            "A historical event. What happened here? What place or object commemorates it? (Swordbreak, Fool's Fall, Firstmeet, Brokenhelm, Mournhaunt, Olgar's Stand, Lostwater, Rojirra's Lament)",
+            "A historical event. What happened here? What place or object commemorates it? (Swordbreak, Fool's Fall, Firstmeet, Brokenhelm, Mournhaunt, Olgar's Stand, Lostwater, Rojirra's Lament)",
+            "A historical event. What happened here? What place or object commemorates it? (Swordbreak, Fool's Fall, Firstmeet, Brokenhelm, Mournhaunt, Olgar's Stand, Lostwater, Rojirra's Lament)",
+            "A word in an Old World language. What culture is represented? What does the name translate to? (Abon, Daveza, Khazu, Arkesh, Ishana, Thala, Tsaeo, Kazeera)",
+            "A word in an Old World language. What culture is represented? What does the name translate to? (Abon, Daveza, Khazu, Arkesh, Ishana, Thala, Tsaeo, Kazeera)",
+            "A word in an Old World language. What culture is represented? What does the name translate to? (Abon, Daveza, Khazu, Arkesh, Ishana, Thala, Tsaeo, Kazeera)",
+            "A word in an Old World language. What culture is represented? What does the name translate to? (Abon, Daveza, Khazu, Arkesh, Ishana, Thala, Tsaeo, Kazeera)",
+            "A word in an Old World language. What culture is represented? What does the name translate to? (Abon, Daveza, Khazu, Arkesh, Ishana, Thala, Tsaeo, Kazeera)",
+            "A word in an Old World language. What culture is represented? What does the name translate to? (Abon, Daveza, Khazu, Arkesh, Ishana, Thala, Tsaeo, Kazeera)",
+            "A word in an Old World language. What culture is represented? What does the name translate to? (Abon, Daveza, Khazu, Arkesh, Ishana, Thala, Tsaeo, Kazeera)",
+            "A word in an Old World language. What culture is represented? What does the name translate to? (Abon, Daveza, Khazu, Arkesh, Ishana, Thala, Tsaeo, Kazeera)",
+            "A word in an Old World language. What culture is represented? What does the name translate to? (Abon, Daveza, Khazu, Arkesh, Ishana, Thala, Tsaeo, Kazeera)",
+            "A word in an Old World language. What culture is represented? What does the name translate to? (Abon, Daveza, Khazu, Arkesh, Ishana, Thala, Tsaeo, Kazeera)",
+            "A word in an Old World language. What culture is represented? What does the name translate to? (Abon, Daveza, Khazu, Arkesh, Ishana, Thala, Tsaeo, Kazeera)",
+            "A word in an Old World language. What culture is represented? What does the name translate to? (Abon, Daveza, Khazu, Arkesh, Ishana, Thala, Tsaeo, Kazeera)",
+            "A word in an Old World language. What culture is represented? What does the name translate to? (Abon, Daveza, Khazu, Arkesh, Ishana, Thala, Tsaeo, Kazeera)",
+            "A word in an Old World language. What culture is represented? What does the name translate to? (Abon, Daveza, Khazu, Arkesh, Ishana, Thala, Tsaeo, Kazeera)",
+            "A word in an Old World language. What culture is represented? What does the name translate to? (Abon, Daveza, Khazu, Arkesh, Ishana, Thala, Tsaeo, Kazeera)",
+            "A season or environmental aspect. What influence does the weather have on this settlement? (Winterhold, Duskmoor, Frostcrag, Springbrook, Summerside, Snowfall, Dusktide, Stormwatch)",
+            "A season or environmental aspect. What influence does the weather have on this settlement? (Winterhold, Duskmoor, Frostcrag, Springbrook, Summerside, Snowfall, Dusktide, Stormwatch)",
+            "A season or environmental aspect. What influence does the weather have on this settlement? (Winterhold, Duskmoor, Frostcrag, Springbrook, Summerside, Snowfall, Dusktide, Stormwatch)",
+            "A season or environmental aspect. What influence does the weather have on this settlement? (Winterhold, Duskmoor, Frostcrag, Springbrook, Summerside, Snowfall, Dusktide, Stormwatch)",
+            "A season or environmental aspect. What influence does the weather have on this settlement? (Winterhold, Duskmoor, Frostcrag, Springbrook, Summerside, Snowfall, Dusktide, Stormwatch)",
+            "A season or environmental aspect. What influence does the weather have on this settlement? (Winterhold, Duskmoor, Frostcrag, Springbrook, Summerside, Snowfall, Dusktide, Stormwatch)",
+            "A season or environmental aspect. What influence does the weather have on this settlement? (Winterhold, Duskmoor, Frostcrag, Springbrook, Summerside, Snowfall, Dusktide, Stormwatch)",
+            "A season or environmental aspect. What influence does the weather have on this settlement? (Winterhold, Duskmoor, Frostcrag, Springbrook, Summerside, Snowfall, Dusktide, Stormwatch)",
+            "A season or environmental aspect. What influence does the weather have on this settlement? (Winterhold, Duskmoor, Frostcrag, Springbrook, Summerside, Snowfall, Dusktide, Stormwatch)",
+            "A season or environmental aspect. What influence does the weather have on this settlement? (Winterhold, Duskmoor, Frostcrag, Springbrook, Summerside, Snowfall, Dusktide, Stormwatch)",
+            "A season or environmental aspect. What influence does the weather have on this settlement? (Winterhold, Duskmoor, Frostcrag, Springbrook, Summerside, Snowfall, Dusktide, Stormwatch)",
+            "A season or environmental aspect. What influence does the weather have on this settlement? (Winterhold, Duskmoor, Frostcrag, Springbrook, Summerside, Snowfall, Dusktide, Stormwatch)",
+            "A season or environmental aspect. What influence does the weather have on this settlement? (Winterhold, Duskmoor, Frostcrag, Springbrook, Summerside, Snowfall, Dusktide, Stormwatch)",
+            "A season or environmental aspect. What influence does the weather have on this settlement? (Winterhold, Duskmoor, Frostcrag, Springbrook, Summerside, Snowfall, Dusktide, Stormwatch)",
+            "A season or environmental aspect. What influence does the weather have on this settlement? (Winterhold, Duskmoor, Frostcrag, Springbrook, Summerside, Snowfall, Dusktide, Stormwatch)",
+            "Something else. A founder or famous settler, a goal or aspiration, a trade or industry (Kei's Hall, Kainline, Ulfstead, Hope, Seawatch, Goldmine, Fishmarket, Weir)",
+            "Something else. A founder or famous settler, a goal or aspiration, a trade or industry (Kei's Hall, Kainline, Ulfstead, Hope, Seawatch, Goldmine, Fishmarket, Weir)",
+            "Something else. A founder or famous settler, a goal or aspiration, a trade or industry (Kei's Hall, Kainline, Ulfstead, Hope, Seawatch, Goldmine, Fishmarket, Weir)",
+            "Something else. A founder or famous settler, a goal or aspiration, a trade or industry (Kei's Hall, Kainline, Ulfstead, Hope, Seawatch, Goldmine, Fishmarket, Weir)",
+            "Something else. A founder or famous settler, a goal or aspiration, a trade or industry (Kei's Hall, Kainline, Ulfstead, Hope, Seawatch, Goldmine, Fishmarket, Weir)",
+            "Something else. A founder or famous settler, a goal or aspiration, a trade or industry (Kei's Hall, Kainline, Ulfstead, Hope, Seawatch, Goldmine, Fishmarket, Weir)",
+            "Something else. A founder or famous settler, a goal or aspiration, a trade or industry (Kei's Hall, Kainline, Ulfstead, Hope, Seawatch, Goldmine, Fishmarket, Weir)",
+            "Something else. A founder or famous settler, a goal or aspiration, a trade or industry (Kei's Hall, Kainline, Ulfstead, Hope, Seawatch, Goldmine, Fishmarket, Weir)",
+            "Something else. A founder or famous settler, a goal or aspiration, a trade or industry (Kei's Hall, Kainline, Ulfstead, Hope, Seawatch, Goldmine, Fishmarket, Weir)",
+            "Something else. A founder or famous settler, a goal or aspiration, a trade or industry (Kei's Hall, Kainline, Ulfstead, Hope, Seawatch, Goldmine, Fishmarket, Weir)",
+        ],
+    },
+    Table {
+        name: "settlement trouble",
+        entries: &[
+            "Outsiders rejected",
+            "Outsiders rejected",
+            "Outsiders rejected",
+            "Dangerous discovery",
+            "Dangerous discovery",
+            "Dangerous discovery",
+            "Dreadful omens",
+            "Dreadful omens",
+            "Dreadful omens",
+            "Natural disaster",
+            "Natural disaster",
+            "Natural disaster",
+            "Old wounds reopened",
+            "Old wounds reopened",
+            "Old wounds reopened",
+            "Important object is lost",
+            "Important object is lost",
+            "Important object is lost",
+            "Someone is captured",
+            "Someone is captured",
+            "Someone is captured",
+            "Mysterious phenomenon",
+            "Mysterious phenomenon",
+            "Mysterious phenomenon",
+            "Revolt against a leader",
+            "Revolt against a leader",
+            "Revolt against a leader",
+            "Vengeful outcast",
+            "Vengeful outcast",
+            "Vengeful outcast",
+            "Rival settlement",
+            "Rival settlement",
+            "Nature strikes back",
+            "Nature strikes back",
+            "Someone is missing",
+            "Someone is missing",
+            "Production halted",
+            "Production halted",
+            "Mysterious murders",
+            "Mysterious murders",
+            "Debt comes due",
+            "Debt comes due",
+            "Unjust leadership",
+            "Unjust leadership",
+            "Disastrous accident",
+            "Disastrous accident",
+            "In league with the enemy",
+            "In league with the enemy",
+            "Raiders prey on the weak",
+            "Raiders prey on the weak",
+            "Cursed past",
+            "Cursed past",
+            "An innocent is accused",
+            "An innocent is accused",
+            "Corrupted by dark magic",
+            "Corrupted by dark magic",
+            "Isolated by brutal weather",
+            "Isolated by brutal weather",
+            "Provisions are scarce",
+            "Provisions are scarce",
+            "Sickness run amok",
+            "Sickness run amok",
+            "Allies become enemies",
+            "Allies become enemies",
+            "Attack is imminent",
+            "Attack is imminent",
+            "Lost caravan",
+            "Lost caravan",
+            "Dark secret revealed",
+            "Dark secret revealed",
+            "Urgent expedition",
+            "Urgent expedition",
+            "A leader falls",
+            "A leader falls",
+            "Families in conflict",
+            "Families in conflict",
+            "Incompetent leadership",
+            "Incompetent leadership",
+            "Reckless warmongering",
+            "Reckless warmongering",
+            "Beast on the hunt",
+            "Beast on the hunt",
+            "Betrayed from within",
+            "Betrayed from within",
+            "Broken truce",
+            "Broken truce",
+            "Wrathful haunt",
+            "Wrathful haunt",
+            "Conflict with firstborn",
+            "Conflict with firstborn",
+            "Trade route blocked",
+            "Trade route blocked",
+            "In the crossfire",
+            "In the crossfire",
+            "Stranger causes discord",
+            "Stranger causes discord",
+            "Important event threatened",
+            "Important event threatened",
+            "Dangerous tradition",
+            "Dangerous tradition",
+        ],
+    },
+    Table {
+        name: "character role",
+        entries: &[
+            "Criminal",
+            "Criminal",
+            "Healer",
+            "Healer",
+            "Bandit",
+            "Bandit",
+            "Guide",
+            "Guide",
+            "Guide",
+            "Performer",
+            "Performer",
+            "Performer",
+            "Miner",
+            "Miner",
+            "Miner",
+            "Mercenary",
+            "Mercenary",
+            "Mercenary",
+            "Outcast",
+            "Outcast",
+            "Outcast",
+            "Vagrant",
+            "Vagrant",
+            "Vagrant",
+            "Forester",
+            "Forester",
+            "Forester",
+            "Traveler",
+            "Traveler",
+            "Traveler",
+            "Mystic",
+            "Mystic",
+            "Mystic",
+            "Priest",
+            "Priest",
+            "Priest",
+            "Sailor",
+            "Sailor",
+            "Sailor",
+            "Pilgrim",
+            "Pilgrim",
+            "Pilgrim",
+            "Thief",
+            "Thief",
+            "Thief",
+            "Adventurer",
+            "Adventurer",
+            "Adventurer",
+            "Forager",
+            "Forager",
+            "Forager",
+            "Leader",
+            "Leader",
+            "Leader",
+            "Guard",
+            "Guard",
+            "Guard",
+            "Guard",
+            "Artisan",
+            "Artisan",
+            "Artisan",
+            "Artisan",
+            "Scout",
+            "Scout",
+            "Scout",
+            "Scout",
+            "Herder",
+            "Herder",
+            "Herder",
+            "Herder",
+            "Fisher",
+            "Fisher",
+            "Fisher",
+            "Fisher",
+            "Warrior",
+            "Warrior",
+            "Warrior",
+            "Warrior",
+            "Warrior",
+            "Hunter",
+            "Hunter",
+            "Hunter",
+            "Hunter",
+            "Hunter",
+            "Raider",
+            "Raider",
+            "Raider",
+            "Raider",
+            "Raider",
+            "Trader",
+            "Trader",
+            "Trader",
+            "Trader",
+            "Trader",
+            "Farmer",
+            "Farmer",
+            "Farmer",
+            "Farmer",
+            "Farmer",
+            "Unusual role",
+        ],
+    },
+    Table {
+        name: "character goal",
+        entries: &[
+            "Obtain an object",
+            "Obtain an object",
+            "Obtain an object",
+            "Make an agreement",
+            "Make an agreement",
+            "Make an agreement",
+            "Build a relationship",
+            "Build a relationship",
+            "Build a relationship",
+            "Undermine a relationship",
+            "Undermine a relationship",
+            "Undermine a relationship",
+            "Seek a truth",
+            "Seek a truth",
+            "Seek a truth",
+            "Pay a debt",
+            "Pay a debt",
+            "Pay a debt",
+            "Refute a falsehood",
+            "Refute a falsehood",
+            "Refute a falsehood",
+            "Harm a rival",
+            "Harm a rival",
+            "Harm a rival",
+            "Cure an ill",
+            "Cure an ill",
+            "Cure an ill",
+            "Find a person",
+            "Find a person",
+            "Find a person",
+            "Find a home",
+            "Find a home",
+            "Find a home",
+            "Seize power",
+            "Seize power",
+            "Seize power",
+            "Restore a relationship",
+            "Restore a relationship",
+            "Restore a relationship",
+            "Create an item",
+            "Create an item",
+            "Create an item",
+            "Travel to a place",
+            "Travel to a place",
+            "Travel to a place",
+            "Secure provisions",
+            "Secure provisions",
+            "Secure provisions",
+            "Rebel against power",
+            "Rebel against power",
+            "Rebel against power",
+            "Collect a debt",
+            "Collect a debt",
+            "Collect a debt",
+            "Protect a secret",
+            "Protect a secret",
+            "Protect a secret",
+            "Spread faith",
+            "Spread faith",
+            "Spread faith",
+            "Enrich themselves",
+            "Enrich themselves",
+            "Enrich themselves",
+            "Protect a person",
+            "Protect a person",
+            "Protect a person",
+            "Protect the status quo",
+            "Protect the status quo",
+            "Protect the status quo",
+            "Advance status",
+            "Advance status",
+            "Advance status",
+            "Defend a place",
+            "Defend a place",
+            "Defend a place",
+            "Avenge a wrong",
+            "Avenge a wrong",
+            "Avenge a wrong",
+            "Fulfill a duty",
+            "Fulfill a duty",
+            "Fulfill a duty",
+            "Gain knowledge",
+            "Gain knowledge",
+            "Gain knowledge",
+            "Prove worthiness",
+            "Prove worthiness",
+            "Prove worthiness",
+            "Find redemption",
+            "Find redemption",
+            "Find redemption",
+            "Escape from something",
+            "Escape from something",
+            "Repair a wrong",
+            "Repair a wrong",
+            "Repair a wrong",
+            "Roll twice",
+            "Roll twice",
+            "Roll twice",
+            "Roll twice",
+            "Roll twice",
+        ],
+    },
+    Table {
+        name: "character descriptor",
+        entries: &[
+            "Stoic",
+            "Attractive",
+            "Passive",
+            "Aloof",
+            "Affectionate",
+            "Generous",
+            "Smug",
+            "Armed",
+            "Clever",
+            "Brave",
+            "Ugly",
+            "Sociable",
+            "Doomed",
+            "Connected",
+            "Bold",
+            "Jealous",
+            "Angry",
+            "Active",
+            "Suspicious",
+            "Hostile",
+            "Hardhearted",
+            "Successful",
+            "Talented",
+            "Experienced",
+            "Deceitful",
+            "Ambitious",
+            "Aggressive",
+            "Conceited",
+            "Proud",
+            "Stern",
+            "Dependent",
+            "Wary",
+            "Strong",
+            "Insightful",
+            "Dangerous",
+            "Quirky",
+            "Cheery",
+            "Disfigured",
+            "Intolerant",
+            "Skilled",
+            "Stingy",
+            "Timid",
+            "Insensitive",
+            "Wild",
+            "Bitter",
+            "Cunning",
+            "Remorseful",
+            "Kind",
+            "Charming",
+            "Oblivious",
+            "Critical",
+            "Cautious",
+            "Resourceful",
+            "Weary",
+            "Wounded",
+            "Anxious",
+            "Powerful",
+            "Athletic",
+            "Driven",
+            "Cruel",
+            "Quiet",
+            "Honest",
+            "Infamous",
+            "Dying",
+            "Reclusive",
+            "Artistic",
+            "Disabled",
+            "Confused",
+            "Manipulative",
+            "Relaxed",
+            "Stealthy",
+            "Confident",
+            "Weak",
+            "Friendly",
+            "Wise",
+            "Influential",
+            "Young",
+            "Adventurous",
+            "Oppressed",
+            "Vengeful",
+            "Cooperative",
+            "Armored",
+            "Apathetic",
+            "Determined",
+            "Loyal",
+            "Sick",
+            "Religious",
+            "Selfish",
+            "Old",
+            "Fervent",
+            "Violent",
+            "Agreeable",
+            "Hot-tempered",
+            "Stubborn",
+            "Incompetent",
+            "Greedy",
+            "Cowardly",
+            "Obsessed",
+            "Careless",
+            "Ironsworn",
+        ],
+    },
+    Table {
+        name: "ironlander name",
+        entries: &[
+            "Solana",
+            "Keelan",
+            "Cadigan",
+            "Sola",
+            "Kodroth",
+            "Kione",
+            "Katja",
+            "Tio",
+            "Artiga",
+            "Eos",
+            "Bastien",
+            "Elli",
+            "Maura",
+            "Haleema",
+            "Abella",
+            "Morter",
+            "Wulan",
+            "Mai",
+            "Farina",
+            "Pearce",
+            "Wynne",
+            "Haf",
+            "Aeddon",
+            "Khinara",
+            "Milla",
+            "Nakata",
+            "Kynan",
+            "Kiah",
+            "Jaggar",
+            "Beca",
+            "Ikram",
+            "Melia",
+            "Sidan",
+            "Deshi",
+            "Tessa",
+            "Sibila",
+            "Morien",
+            "Mona",
+            "Padma",
+            "Avella",
+            "Naila",
+            "Lio",
+            "Cera",
+            "Ithela",
+            "Zhan",
+            "Kaivan",
+            "Valeri",
+            "Hirsham",
+            "Pemba",
+            "Edda",
+            "Lestra",
+            "Lago",
+            "Leesa",
+            "Khulan",
+            "Cathal",
+            "Yaro",
+            "Lyra",
+            "Vanko",
+            "Rona",
+            "Iriel",
+            "Segura",
+            "Gethin",
+            "Bataar",
+            "Basira",
+            "Joa",
+            "Glynn",
+            "Toran",
+            "Arasen",
+            "Kuron",
+            "Griff",
+            "Owena",
+            "Adda",
+            "Euda",
+            "Minna",
+            "Anton",
+            "Tarik",
+            "Sorcha",
+            "Brenna",
+            "Kell",
+            "Inga",
+            "Ulfa",
+            "Torvin",
+            "Nia",
+            "Dagny",
+            "Ravi",
+            "Sefa",
+            "Hakon",
+            "Ysolde",
+            "Emrys",
+            "Kali",
+            "Bryn",
+            "Aster",
+            "Jorund",
+            "Mira",
+            "Teodor",
+            "Saoirse",
+            "Okan",
+            "Vesna",
+            "Halvard",
+            "Ashk",
+        ],
+    },
+    Table {
+        name: "elf name",
+        entries: &[
+            "Arsula",
+            "Arsula",
+            "Naidita",
+            "Naidita",
+            "Belesunna",
+            "Belesunna",
+            "Vidarna",
+            "Vidarna",
+            "Ninsunu",
+            "Ninsunu",
+            "Balathu",
+            "Balathu",
+            "Dorosi",
+            "Dorosi",
+            "Gezera",
+            "Gezera",
+            "Zursan",
+            "Zursan",
+            "Seleeku",
+            "Seleeku",
+            "Utamara",
+            "Utamara",
+            "Nebakay",
+            "Nebakay",
+            "Dismashk",
+            "Dismashk",
+            "Mitunu",
+            "Mitunu",
+            "Atani",
+            "Atani",
+            "Kinzura",
+            "Kinzura",
+            "Sumula",
+            "Sumula",
+            "Ukames",
+            "Ukames",
+            "Ahmeshki",
+            "Ahmeshki",
+            "Ilsit",
+            "Ilsit",
+            "Mayatanay",
+            "Mayatanay",
+            "Etana",
+            "Etana",
+            "Gamanna",
+            "Gamanna",
+            "Nessana",
+            "Nessana",
+            "Uralar",
+            "Uralar",
+            "Tishetu",
+            "Tishetu",
+            "Leucia",
+            "Leucia",
+            "Sotarra",
+            "Sotarra",
+            "Baraku",
+            "Baraku",
+            "Ereshki",
+            "Ereshki",
+            "Ninkadi",
+            "Ninkadi",
+            "Ilishar",
+            "Ilishar",
+            "Zahmeni",
+            "Zahmeni",
+            "Anusha",
+            "Anusha",
+            "Suinnu",
+            "Suinnu",
+            "Kisharra",
+            "Kisharra",
+            "Eduna",
+            "Eduna",
+            "Tabrani",
+            "Tabrani",
+            "Murana",
+            "Murana",
+            "Enlusa",
+            "Enlusa",
+            "Ashuri",
+            "Ashuri",
+            "Nindara",
+            "Nindara",
+            "Lamashi",
+            "Lamashi",
+            "Sikutu",
+            "Sikutu",
+            "Ubaru",
+            "Ubaru",
+            "Ishtana",
+            "Ishtana",
+            "Amarsin",
+            "Amarsin",
+            "Dumuzi",
+            "Dumuzi",
+            "Shalana",
+            "Shalana",
+            "Kuruneki",
+            "Kuruneki",
+        ],
+    },
+    Table {
+        name: "giant name",
+        entries: &[
+            "Chony",
+            "Chony",
+            "Chony",
+            "Chony",
+            "Chony",
+            "Chony",
+            "Chony",
+            "Chony",
+            "Chony",
+            "Chony",
+            "Chony",
+            "Chony",
+            "Chony",
+            "Chony",
+            "Chony",
+            "Chony",
+            "Chony",
+            "Chony",
+            "Chony",
+            "Chony",
+            "Banda",
+            "Banda",
+            "Banda",
+            "Banda",
+            "Banda",
+            "Banda",
+            "Banda",
+            "Banda",
+            "Banda",
+            "Banda",
+            "Banda",
+            "Banda",
+            "Banda",
+            "Banda",
+            "Banda",
+            "Banda",
+            "Banda",
+            "Banda",
+            "Banda",
+            "Banda",
+            "Jochu",
+            "Jochu",
+            "Jochu",
+            "Jochu",
+            "Jochu",
+            "Jochu",
+            "Jochu",
+            "Jochu",
+            "Jochu",
+            "Jochu",
+            "Jochu",
+            "Jochu",
+            "Jochu",
+            "Jochu",
+            "Jochu",
+            "Jochu",
+            "Jochu",
+            "Jochu",
+            "Jochu",
+            "Jochu",
+            "Kira",
+            "Kira",
+            "Kira",
+            "Kira",
+            "Kira",
+            "Kira",
+            "Kira",
+            "Kira",
+            "Kira",
+            "Kira",
+            "Kira",
+            "Kira",
+            "Kira",
+            "Kira",
+            "Kira",
+            "Kira",
+            "Kira",
+            "Kira",
+            "Kira",
+            "Kira",
+            "Khatir",
+            "Khatir",
+            "Khatir",
+            "Khatir",
+            "Khatir",
+            "Khatir",
+            "Khatir",
+            "Khatir",
+            "Khatir",
+            "Khatir",
+            "Khatir",
+            "Khatir",
+            "Khatir",
+            "Khatir",
+            "Khatir",
+            "Khatir",
+            "Khatir",
+            "Khatir",
+            "Khatir",
+            "Khatir",
+        ],
+    },
+    Table {
+        name: "varou name",
+        entries: &[
+            "Kendi",
+            "Kendi",
+            "Kendi",
+            "Kendi",
+            "Kendi",
+            "Kendi",
+            "Kendi",
+            "Kendi",
+            "Kendi",
+            "Kendi",
+            "Kendi",
+            "Kendi",
+            "Kendi",
+            "Kendi",
+            "Kendi",
+            "Kendi",
+            "Kendi",
+            "Kendi",
+            "Kendi",
+            "Kendi",
+            "Vata",
+            "Vata",
+            "Vata",
+            "Vata",
+            "Vata",
+            "Vata",
+            "Vata",
+            "Vata",
+            "Vata",
+            "Vata",
+            "Vata",
+            "Vata",
+            "Vata",
+            "Vata",
+            "Vata",
+            "Vata",
+            "Vata",
+            "Vata",
+            "Vata",
+            "Vata",
+            "Zora",
+            "Zora",
+            "Zora",
+            "Zora",
+            "Zora",
+            "Zora",
+            "Zora",
+            "Zora",
+            "Zora",
+            "Zora",
+            "Zora",
+            "Zora",
+            "Zora",
+            "Zora",
+            "Zora",
+            "Zora",
+            "Zora",
+            "Zora",
+            "Zora",
+            "Zora",
+            "Jasna",
+            "Jasna",
+            "Jasna",
+            "Jasna",
+            "Jasna",
+            "Jasna",
+            "Jasna",
+            "Jasna",
+            "Jasna",
+            "Jasna",
+            "Jasna",
+            "Jasna",
+            "Jasna",
+            "Jasna",
+            "Jasna",
+            "Jasna",
+            "Jasna",
+            "Jasna",
+            "Jasna",
+            "Jasna",
+            "Lazar",
+            "Lazar",
+            "Lazar",
+            "Lazar",
+            "Lazar",
+            "Lazar",
+            "Lazar",
+            "Lazar",
+            "Lazar",
+            "Lazar",
+            "Lazar",
+            "Lazar",
+            "Lazar",
+            "Lazar",
+            "Lazar",
+            "Lazar",
+            "Lazar",
+            "Lazar",
+            "Lazar",
+            "Lazar",
+        ],
+    },
+    Table {
+        name: "troll name",
+        entries: &[
+            "Rattle",
+            "Rattle",
+            "Rattle",
+            "Rattle",
+            "Rattle",
+            "Rattle",
+            "Rattle",
+            "Rattle",
+            "Rattle",
+            "Rattle",
+            "Rattle",
+            "Rattle",
+            "Rattle",
+            "Rattle",
+            "Rattle",
+            "Rattle",
+            "Rattle",
+            "Rattle",
+            "Rattle",
+            "Rattle",
+            "Scratch",
+            "Scratch",
+            "Scratch",
+            "Scratch",
+            "Scratch",
+            "Scratch",
+            "Scratch",
+            "Scratch",
+            "Scratch",
+            "Scratch",
+            "Scratch",
+            "Scratch",
+            "Scratch",
+            "Scratch",
+            "Scratch",
+            "Scratch",
+            "Scratch",
+            "Scratch",
+            "Scratch",
+            "Scratch",
+            "Wallow",
+            "Wallow",
+            "Wallow",
+            "Wallow",
+            "Wallow",
+            "Wallow",
+            "Wallow",
+            "Wallow",
+            "Wallow",
+            "Wallow",
+            "Wallow",
+            "Wallow",
+            "Wallow",
+            "Wallow",
+            "Wallow",
+            "Wallow",
+            "Wallow",
+            "Wallow",
+            "Wallow",
+            "Wallow",
+            "Groak",
+            "Groak",
+            "Groak",
+            "Groak",
+            "Groak",
+            "Groak",
+            "Groak",
+            "Groak",
+            "Groak",
+            "Groak",
+            "Groak",
+            "Groak",
+            "Groak",
+            "Groak",
+            "Groak",
+            "Groak",
+            "Groak",
+            "Groak",
+            "Groak",
+            "Groak",
+            "Gimble",
+            "Gimble",
+            "Gimble",
+            "Gimble",
+            "Gimble",
+            "Gimble",
+            "Gimble",
+            "Gimble",
+            "Gimble",
+            "Gimble",
+            "Gimble",
+            "Gimble",
+            "Gimble",
+            "Gimble",
+            "Gimble",
+            "Gimble",
+            "Gimble",
+            "Gimble",
+            "Gimble",
+            "Gimble",
+        ],
+    },
+    Table {
+        name: "combat action",
+        entries: &[
+            "Compel a surrender",
+            "Compel a surrender",
+            "Compel a surrender",
+            "Coordinate with allies",
+            "Coordinate with allies",
+            "Coordinate with allies",
+            "Gather reinforcements",
+            "Gather reinforcements",
+            "Gather reinforcements",
+            "Seize something or someone",
+            "Seize something or someone",
+            "Seize something or someone",
+            "Seize something or someone",
+            "Provoke a reckless response",
+            "Provoke a reckless response",
+            "Provoke a reckless response",
+            "Provoke a reckless response",
+            "Intimidate or frighten",
+            "Intimidate or frighten",
+            "Intimidate or frighten",
+            "Intimidate or frighten",
+            "Reveal a surprising truth",
+            "Reveal a surprising truth",
+            "Reveal a surprising truth",
+            "Reveal a surprising truth",
+            "Shift focus to someone or something else",
+            "Shift focus to someone or something else",
+            "Shift focus to someone or something else",
+            "Shift focus to someone or something else",
+            "Destroy something, or render it useless",
+            "Destroy something, or render it useless",
+            "Destroy something, or render it useless",
+            "Destroy something, or render it useless",
+            "Take a decisive action",
+            "Take a decisive action",
+            "Take a decisive action",
+            "Take a decisive action",
+            "Take a decisive action",
+            "Take a decisive action",
+            "Reinforce defenses",
+            "Reinforce defenses",
+            "Reinforce defenses",
+            "Reinforce defenses",
+            "Reinforce defenses",
+            "Reinforce defenses",
+            "Ready an action",
+            "Ready an action",
+            "Ready an action",
+            "Ready an action",
+            "Ready an action",
+            "Ready an action",
+            "Ready an action",
+            "Use the terrain to gain advantage",
+            "Use the terrain to gain advantage",
+            "Use the terrain to gain advantage",
+            "Use the terrain to gain advantage",
+            "Use the terrain to gain advantage",
+            "Use the terrain to gain advantage",
+            "Use the terrain to gain advantage",
+            "Use the terrain to gain advantage",
+            "Leverage the advantage of a weapon or ability",
+            "Leverage the advantage of a weapon or ability",
+            "Leverage the advantage of a weapon or ability",
+            "Leverage the advantage of a weapon or ability",
+            "Leverage the advantage of a weapon or ability",
+            "Leverage the advantage of a weapon or ability",
+            "Leverage the advantage of a weapon or ability",
+            "Leverage the advantage of a weapon or ability",
+            "Create an opportunity",
+            "Create an opportunity",
+            "Create an opportunity",
+            "Create an opportunity",
+            "Create an opportunity",
+            "Create an opportunity",
+            "Create an opportunity",
+            "Create an opportunity",
+            "Create an opportunity",
+            "Create an opportunity",
+            "Attack with precision",
+            "Attack with precision",
+            "Attack with precision",
+            "Attack with precision",
+            "Attack with precision",
+            "Attack with precision",
+            "Attack with precision",
+            "Attack with precision",
+            "Attack with precision",
+            "Attack with precision",
+            "Attack with precision",
+            "Attack with power",
+            "Attack with power",
+            "Attack with power",
+            "Attack with power",
+            "Attack with power",
+            "Attack with power",
+            "Attack with power",
+            "Attack with power",
+            "Attack with power",
+            "Attack with power",
+            "Take a completely unexpected action",
+        ],
+    },
+    Table {
+        name: "mystic backlash",
+        entries: &[
+            "Your ritual has the opposite effect",
+            "Your ritual has the opposite effect",
+            "Your ritual has the opposite effect",
+            "Your ritual has the opposite effect",
+            "You are sapped of strength",
+            "You are sapped of strength",
+            "You are sapped of strength",
+            "You are sapped of strength",
+            "Your friend, ally, or companion is adversely affected",
+            "Your friend, ally, or companion is adversely affected",
+            "Your friend, ally, or companion is adversely affected",
+            "Your friend, ally, or companion is adversely affected",
+            "You destroy an important object",
+            "You destroy an important object",
+            "You destroy an important object",
+            "You destroy an important object",
+            "You inadvertently summon a horror",
+            "You inadvertently summon a horror",
+            "You inadvertently summon a horror",
+            "You inadvertently summon a horror",
+            "You collapse, and drift into a troubled sleep",
+            "You collapse, and drift into a troubled sleep",
+            "You collapse, and drift into a troubled sleep",
+            "You collapse, and drift into a troubled sleep",
+            "You undergo a physical torment which leaves its mark upon you",
+            "You undergo a physical torment which leaves its mark upon you",
+            "You undergo a physical torment which leaves its mark upon you",
+            "You undergo a physical torment which leaves its mark upon you",
+            "You hear ghostly voices whispering of dark portents",
+            "You hear ghostly voices whispering of dark portents",
+            "You hear ghostly voices whispering of dark portents",
+            "You hear ghostly voices whispering of dark portents",
+            "You are lost in shadow, and find yourself in another place without memory of how you got there",
+            "You are lost in shadow, and find yourself in another place without memory of how you got there",
+            "You are lost in shadow, and find yourself in another place without memory of how you got there",
+            "You are lost in shadow, and find yourself in another place without memory of how you got there",
+            "You alert someone or something to your presence",
+            "You alert someone or something to your presence",
+            "You alert someone or something to your presence",
+            "You alert someone or something to your presence",
+            "You are not yourself, and act against a friend, ally, or companion",
+            "You are not yourself, and act against a friend, ally, or companion",
+            "You are not yourself, and act against a friend, ally, or companion",
+            "You are not yourself, and act against a friend, ally, or companion",
+            "You affect or damage your surroundings, causing a disturbance or potential harm",
+            "You affect or damage your surroundings, causing a disturbance or potential harm",
+            "You affect or damage your surroundings, causing a disturbance or potential harm",
+            "You affect or damage your surroundings, causing a disturbance or potential harm",
+            "You waste resources",
+            "You waste resources",
+            "You waste resources",
+            "You waste resources",
+            "You suffer the loss of a sense for several hours",
+            "You suffer the loss of a sense for several hours",
+            "You suffer the loss of a sense for several hours",
+            "You suffer the loss of a sense for several hours",
+            "You lose your connection to magic for a day or so, and cannot perform rituals",
+            "You lose your connection to magic for a day or so, and cannot perform rituals",
+            "You lose your connection to magic for a day or so, and cannot perform rituals",
+            "You lose your connection to magic for a day or so, and cannot perform rituals",
+            "Your ritual affects the target in an unexpected and problematic way",
+            "Your ritual affects the target in an unexpected and problematic way",
+            "Your ritual affects the target in an unexpected and problematic way",
+            "Your ritual affects the target in an unexpected and problematic way",
+            "Your ritual reveals a surprising and troubling truth",
+            "Your ritual reveals a surprising and troubling truth",
+            "Your ritual reveals a surprising and troubling truth",
+            "Your ritual reveals a surprising and troubling truth",
+            "You are tempted by dark powers",
+            "You are tempted by dark powers",
+            "You are tempted by dark powers",
+            "You are tempted by dark powers",
+            "You see a troubling vision of your future",
+            "You see a troubling vision of your future",
+            "You see a troubling vision of your future",
+            "You see a troubling vision of your future",
+            "You can't perform this ritual again until you acquire an important component",
+            "You can't perform this ritual again until you acquire an important component",
+            "You can't perform this ritual again until you acquire an important component",
+            "You can't perform this ritual again until you acquire an important component",
+            "You develop a strange fear or compulsion",
+            "You develop a strange fear or compulsion",
+            "You develop a strange fear or compulsion",
+            "You develop a strange fear or compulsion",
+            "Your ritual causes creatures to exhibit strange behavior",
+            "Your ritual causes creatures to exhibit strange behavior",
+            "Your ritual causes creatures to exhibit strange behavior",
+            "Your ritual causes creatures to exhibit strange behavior",
+            "You are visited by a spirit of the dead, who demands something of you",
+            "You are visited by a spirit of the dead, who demands something of you",
+            "You are visited by a spirit of the dead, who demands something of you",
+            "You are visited by a spirit of the dead, who demands something of you",
+            "Your magic leaves a lingering mark on the place, drawing unwanted attention",
+            "Your magic leaves a lingering mark on the place, drawing unwanted attention",
+            "Your magic leaves a lingering mark on the place, drawing unwanted attention",
+            "Your magic leaves a lingering mark on the place, drawing unwanted attention",
+            "Roll twice more on this table. Both results occur. If they are the same result, make it worse",
+            "Roll twice more on this table. Both results occur. If they are the same result, make it worse",
+            "Roll twice more on this table. Both results occur. If they are the same result, make it worse",
+            "Roll twice more on this table. Both results occur. If they are the same result, make it worse",
+        ],
+    },
+    Table {
+        name: "major plot twist",
+        entries: &[
+            "It was all a diversion",
+            "It was all a diversion",
+            "It was all a diversion",
+            "It was all a diversion",
+            "It was all a diversion",
+            "A dark secret is revealed",
+            "A dark secret is revealed",
+            "A dark secret is revealed",
+            "A dark secret is revealed",
+            "A dark secret is revealed",
+            "A trap is sprung",
+            "A trap is sprung",
+            "A trap is sprung",
+            "A trap is sprung",
+            "A trap is sprung",
+            "An assumption is revealed to be false",
+            "An assumption is revealed to be false",
+            "An assumption is revealed to be false",
+            "An assumption is revealed to be false",
+            "An assumption is revealed to be false",
+            "A secret alliance is revealed",
+            "A secret alliance is revealed",
+            "A secret alliance is revealed",
+            "A secret alliance is revealed",
+            "A secret alliance is revealed",
+            "Your actions benefit an enemy",
+            "Your actions benefit an enemy",
+            "Your actions benefit an enemy",
+            "Your actions benefit an enemy",
+            "Your actions benefit an enemy",
+            "Someone returns unexpectedly",
+            "Someone returns unexpectedly",
+            "Someone returns unexpectedly",
+            "Someone returns unexpectedly",
+            "Someone returns unexpectedly",
+            "A more dangerous foe is revealed",
+            "A more dangerous foe is revealed",
+            "A more dangerous foe is revealed",
+            "A more dangerous foe is revealed",
+            "A more dangerous foe is revealed",
+            "You and an enemy share a common goal",
+            "You and an enemy share a common goal",
+            "You and an enemy share a common goal",
+            "You and an enemy share a common goal",
+            "You and an enemy share a common goal",
+            "A true identity is revealed",
+            "A true identity is revealed",
+            "A true identity is revealed",
+            "A true identity is revealed",
+            "A true identity is revealed",
+            "You are betrayed by someone who was trusted",
+            "You are betrayed by someone who was trusted",
+            "You are betrayed by someone who was trusted",
+            "You are betrayed by someone who was trusted",
+            "You are betrayed by someone who was trusted",
+            "You are too late",
+            "You are too late",
+            "You are too late",
+            "You are too late",
+            "You are too late",
+            "The true enemy is revealed",
+            "The true enemy is revealed",
+            "The true enemy is revealed",
+            "The true enemy is revealed",
+            "The true enemy is revealed",
+            "The enemy gains new allies",
+            "The enemy gains new allies",
+            "The enemy gains new allies",
+            "The enemy gains new allies",
+            "The enemy gains new allies",
+            "A new danger appears",
+            "A new danger appears",
+            "A new danger appears",
+            "A new danger appears",
+            "A new danger appears",
+            "Someone or something goes missing",
+            "Someone or something goes missing",
+            "Someone or something goes missing",
+            "Someone or something goes missing",
+            "Someone or something goes missing",
+            "The truth of a relationship is revealed",
+            "The truth of a relationship is revealed",
+            "The truth of a relationship is revealed",
+            "The truth of a relationship is revealed",
+            "The truth of a relationship is revealed",
+            "Two seemingly unrelated situations are shown to be connected",
+            "Two seemingly unrelated situations are shown to be connected",
+            "Two seemingly unrelated situations are shown to be connected",
+            "Two seemingly unrelated situations are shown to be connected",
+            "Two seemingly unrelated situations are shown to be connected",
+            "Unexpected powers or abilities are revealed",
+            "Unexpected powers or abilities are revealed",
+            "Unexpected powers or abilities are revealed",
+            "Unexpected powers or abilities are revealed",
+            "Unexpected powers or abilities are revealed",
+            "Roll twice more on this table. Both results occur. If they are the same result, make it more dramatic",
+            "Roll twice more on this table. Both results occur. If they are the same result, make it more dramatic",
+            "Roll twice more on this table. Both results occur. If they are the same result, make it more dramatic",
+            "Roll twice more on this table. Both results occur. If they are the same result, make it more dramatic",
+            "Roll twice more on this table. Both results occur. If they are the same result, make it more dramatic",
+        ],
+    },
+    Table {
+        name: "challenge rank",
+        entries: &[
+            "Troublesome",
+            "Troublesome",
+            "Troublesome",
+            "Troublesome",
+            "Troublesome",
+            "Troublesome",
+            "Troublesome",
+            "Troublesome",
+            "Troublesome",
+            "Troublesome",
+            "Troublesome",
+            "Troublesome",
+            "Troublesome",
+            "Troublesome",
+            "Troublesome",
+            "Troublesome",
+            "Troublesome",
+            "Troublesome",
+            "Troublesome",
+            "Troublesome",
+            "Dangerous",
+            "Dangerous",
+            "Dangerous",
+            "Dangerous",
+            "Dangerous",
+            "Dangerous",
+            "Dangerous",
+            "Dangerous",
+            "Dangerous",
+            "Dangerous",
+            "Dangerous",
+            "Dangerous",
+            "Dangerous",
+            "Dangerous",
+            "Dangerous",
+            "Dangerous",
+            "Dangerous",
+            "Dangerous",
+            "Dangerous",
+            "Dangerous",
+            "Dangerous",
+            "Dangerous",
+            "Dangerous",
+            "Dangerous",
+            "Dangerous",
+            "Dangerous",
+            "Dangerous",
+            "Dangerous",
+            "Dangerous",
+            "Dangerous",
+            "Dangerous",
+            "Dangerous",
+            "Dangerous",
+            "Dangerous",
+            "Dangerous",
+            "Formidable",
+            "Formidable",
+            "Formidable",
+            "Formidable",
+            "Formidable",
+            "Formidable",
+            "Formidable",
+            "Formidable",
+            "Formidable",
+            "Formidable",
+            "Formidable",
+            "Formidable",
+            "Formidable",
+            "Formidable",
+            "Formidable",
+            "Formidable",
+            "Formidable",
+            "Formidable",
+            "Formidable",
+            "Formidable",
+            "Formidable",
+            "Formidable",
+            "Formidable",
+            "Formidable",
+            "Formidable",
+            "Extreme",
+            "Extreme",
+            "Extreme",
+            "Extreme",
+            "Extreme",
+            "Extreme",
+            "Extreme",
+            "Extreme",
+            "Extreme",
+            "Extreme",
+            "Extreme",
+            "Extreme",
+            "Extreme",
+            "Epic",
+            "Epic",
+            "Epic",
+            "Epic",
+            "Epic",
+            "Epic",
+            "Epic",
+        ],
+    },
+];
+
+// Odds of a yes/no question: the answer is yes when the d100 is at least the value
+static ODDS: &[(&str, u64)] = &[
+    ("almost certain", 11),
+    ("likely", 26),
+    ("50/50", 51),
+    ("unlikely", 76),
+    ("small chance", 91),
+];
+
+enum Question {
+    Table(&'static Table),
+    Odds(&'static str, u64),
+}
+
+impl Oracle {
+    fn question(&self, params: &str) -> Result<Question, String> {
+        let params = params
+            .split_whitespace()
+            .collect::<Vec<_>>()
+            .join(" ")
+            .to_lowercase();
+        if let Some(table) = TABLES.iter().find(|table| table.name == params) {
+            Ok(Question::Table(table))
+        } else if let Some((odds, min)) = ODDS.iter().find(|(odds, _)| *odds == params) {
+            Ok(Question::Odds(odds, *min))
+        } else {
+            Err(format!("Unknown oracle `{}`. {}", params, list()))
+        }
+    }
+}
+
+/// The tables and odds `/oracle` knows
+pub fn list() -> String {
+    let tables: Vec<&str> = TABLES.iter().map(|table| table.name).collect();
+    let odds: Vec<&str> = ODDS.iter().map(|(odds, _)| *odds).collect();
+    format!(
+        "Tables: {}. Yes/no questions: {}",
+        tables.join(", "),
+        odds.join(", ")
+    )
+}
+
+impl GameSystem for Oracle {
+    fn name(&self) -> &'static str {
+        "oracle"
+    }
+
+    fn syntax(&self) -> &'static str {
+        "oracle(table or odds)"
+    }
+
+    fn description(&self) -> &'static str {
+        "Ironsworn oracles: a prompt from a table of the rulebook, ex: `oracle(pay the price)`, or \
+         the answer to a yes/no question with its odds, ex: `oracle(likely)`. A match (11, 22…) \
+         means something unexpected happens"
+    }
+
+    fn expression(&self, params: &str) -> Result<String, String> {
+        Ok(match self.question(params)? {
+            Question::Table(table) => format!("1d{}", table.entries.len()),
+            Question::Odds(_, _) => "1d100".to_string(),
+        })
+    }
+
+    fn interpret(&self, params: &str, res: RollResult) -> Result<Outcome, String> {
+        let value = res
+            .as_single()
+            .and_then(|res| {
+                res.get_history().iter().find_map(|h| match h {
+                    RollHistory::Roll(dices) => dices.first().map(|d| d.res),
+                    _ => None,
+                })
+            })
+            .ok_or_else(|| syntax_error(self))?;
+        let mut text = match self.question(params)? {
+            Question::Table(table) => format!(
+                "{} `[{}]` → **{}**",
+                table.name,
+                value,
+                table.entries[value as usize - 1]
+            ),
+            Question::Odds(odds, min) => {
+                let mut s = format!(
+                    "{} `[{}]` → **{}**",
+                    odds,
+                    value,
+                    if value >= min { "Yes" } else { "No" }
+                );
+                // 100 is 00, a match as well
+                if value % 11 == 0 || value == 100 {
+                    s.push_str(", it's a match!");
+                }
+                s
+            }
+        };
+        if let Some(reason) = res.get_reason() {
+            text.push_str(&format!(", Reason: `{}`", reason));
+        }
+        Ok(Outcome::text(text))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::rng::rng_from_seed;
+
+    #[test]
+    fn oracle_tables() {
+        let names: Vec<&str> = TABLES.iter().map(|table| table.name).collect();
+        assert_eq!(
+            vec![
+                "action",
+                "theme",
+                "pay the price",
+                "region",
+                "location",
+                "coastal waters",
+                "location descriptor",
+                "settlement name",
+                "settlement trouble",
+                "character role",
+                "character goal",
+                "character descriptor",
+                "ironlander name",
+                "elf name",
+                "giant name",
+                "varou name",
+                "troll name",
+                "combat action",
+                "mystic backlash",
+                "major plot twist",
+                "challenge rank",
+            ],
+            names
+        );
+        for table in TABLES {
+            assert_eq!(100, table.entries.len(), "{}", table.name);
+        }
+        assert_eq!(Ok("1d100".to_string()), Oracle.expression("Action"));
+        assert_eq!(Ok("1d100".to_string()), Oracle.expression("50/50"));
+        assert!(Oracle.expression("weather").is_err());
+
+        let roll = |params: &str| {
+            let roller = caith::Roller::new(&Oracle.expression(params).unwrap()).unwrap();
+            let res = roller.roll_with(&mut rng_from_seed(2)).unwrap();
+            Oracle.interpret(params, res).unwrap().text
+        };
+        assert_eq!("theme `[8]` → **Weapon**", roll("theme"));
+        assert_eq!(
+            "pay the price `[8]` → **A person or community you care about is exposed to danger**",
+            roll("Pay  the price")
+        );
+        assert_eq!(
+            "challenge rank `[8]` → **Troublesome**",
+            roll("challenge rank")
+        );
+        assert_eq!("unlikely `[8]` → **No**", roll("unlikely"));
+    }
+}
@@ -0,0 +1,56 @@
+use caith::RollResult;
+
+use super::{syntax_error, GameSystem, Outcome};
+
+/// OVA: The Anime Role-Playing Game
+pub struct Ova;
+
+impl Ova {
+    fn number(&self, params: &str) -> Result<i32, String> {
+        match params.trim().parse::<i32>() {
+            Ok(0) => Err("Number can't be zero".to_string()),
+            Ok(number) => Ok(number),
+            Err(_) => Err(syntax_error(self)),
+        }
+    }
+}
+
+impl GameSystem for Ova {
+    fn name(&self) -> &'static str {
+        "ova"
+    }
+
+    fn syntax(&self) -> &'static str {
+        "ova(number)"
+    }
+
+    fn description(&self) -> &'static str {
+        "OVA: The Anime Role-Playing Game, a negative number rolls and keeps the lowest dice"
+    }
+
+    fn expression(&self, params: &str) -> Result<String, String> {
+        Ok(format!("{}d6", self.number(params)?.abs()))
+    }
+
+    fn interpret(&self, params: &str, res: RollResult) -> Result<Outcome, String> {
+        caith::helpers::compute_ova(&res, self.number(params)?)
+            .map(Outcome::new)
+            .map_err(|err| err.to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn ova_expression() {
+        assert_eq!(Ok("3d6".to_string()), Ova.expression("3"));
+        assert_eq!(Ok("2d6".to_string()), Ova.expression(" -2 "));
+        assert!(Ova.expression("0").is_err());
+        assert_eq!(
+            Err("Syntax error, expected: `ova(number)`".to_string()),
+            Ova.expression("many")
+        );
+    }
+}
@@ -0,0 +1,150 @@
+use caith::RollResult;
+
+use super::{syntax_error, with_outcome, GameSystem, Outcome};
+
+/// Powered by the Apocalypse: 2d6 + modifier, a strong hit at 10+, a weak hit at 7-9
+pub struct Pbta;
+
+#[derive(Debug, PartialEq)]
+enum Edge {
+    None,
+    // roll 3d6 and keep the 2 highest
+    Advantage,
+    // roll 3d6 and keep the 2 lowest
+    Disadvantage,
+}
+
+#[derive(Debug, PartialEq)]
+struct PbtaParams {
+    modifier: i64,
+    edge: Edge,
+    strong_hit: i64,
+    weak_hit: i64,
+}
+
+impl Pbta {
+    // `modifier[, adv|dis][, strong/weak]`
+    fn params(&self, params: &str) -> Result<PbtaParams, String> {
+        let mut parts = params.split(',').map(str::trim);
+        let modifier = match parts.next().unwrap_or_default() {
+            "" => 0,
+            modifier => modifier
+                .trim_start_matches('+')
+                .parse()
+                .map_err(|_| syntax_error(self))?,
+        };
+        let mut parsed = PbtaParams {
+            modifier,
+            edge: Edge::None,
+            strong_hit: 10,
+            weak_hit: 7,
+        };
+        for part in parts {
+            match part.to_lowercase().as_str() {
+                "adv" | "advantage" => parsed.edge = Edge::Advantage,
+                "dis" | "disadvantage" => parsed.edge = Edge::Disadvantage,
+                thresholds => {
+                    let (strong, weak) = thresholds
+                        .split_once('/')
+                        .ok_or_else(|| syntax_error(self))?;
+                    parsed.strong_hit = strong.trim().parse().map_err(|_| syntax_error(self))?;
+                    parsed.weak_hit = weak.trim().parse().map_err(|_| syntax_error(self))?;
+                    if parsed.weak_hit > parsed.strong_hit {
+                        return Err(
+                            "The strong hit threshold must be above the weak hit one".to_string()
+                        );
+                    }
+                }
+            }
+        }
+        Ok(parsed)
+    }
+}
+
+impl GameSystem for Pbta {
+    fn name(&self) -> &'static str {
+        "pbta"
+    }
+
+    fn syntax(&self) -> &'static str {
+        "pbta(modifier[, adv|dis][, strong/weak])"
+    }
+
+    fn description(&self) -> &'static str {
+        "Powered by the Apocalypse, 2d6 + modifier: strong hit, weak hit or miss. `adv` rolls 3d6 \
+         and keeps the 2 highest, `dis` the 2 lowest. The thresholds are 10/7 by default"
+    }
+
+    fn expression(&self, params: &str) -> Result<String, String> {
+        let params = self.params(params)?;
+        let dices = match params.edge {
+            Edge::None => "2d6",
+            Edge::Advantage => "3d6K2",
+            Edge::Disadvantage => "3d6k2",
+        };
+        Ok(match params.modifier {
+            0 => dices.to_string(),
+            m if m > 0 => format!("{} + {}", dices, m),
+            m => format!("{} - {}", dices, -m),
+        })
+    }
+
+    fn interpret(&self, params: &str, res: RollResult) -> Result<Outcome, String> {
+        let params = self.params(params)?;
+        let total = res
+            .as_single()
+            .ok_or_else(|| syntax_error(self))?
+            .get_total();
+        let outcome = if total >= params.strong_hit {
+            "**Strong hit** 🥳"
+        } else if total >= params.weak_hit {
+            "**Weak hit**"
+        } else {
+            "**Miss** 🤬"
+        };
+        Ok(Outcome::text(with_outcome(&res, outcome)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::rng::rng_from_seed;
+
+    #[test]
+    fn pbta_expression() {
+        assert_eq!(Ok("2d6 + 2".to_string()), Pbta.expression("+2"));
+        assert_eq!(Ok("2d6 - 1".to_string()), Pbta.expression("-1"));
+        assert_eq!(Ok("2d6".to_string()), Pbta.expression(""));
+        assert_eq!(Ok("3d6K2 + 1".to_string()), Pbta.expression("1, adv"));
+        assert_eq!(Ok("3d6k2".to_string()), Pbta.expression("0, dis"));
+        assert!(Pbta.expression("+2, sometimes").is_err());
+        assert!(Pbta.expression("+2, 7/10").is_err());
+    }
+
+    fn outcome(params: &str, seed: u64) -> String {
+        let roller = caith::Roller::new(&Pbta.expression(params).unwrap()).unwrap();
+        let res = roller.roll_with(&mut rng_from_seed(seed)).unwrap();
+        Pbta.interpret(params, res).unwrap().text
+    }
+
+    #[test]
+    fn pbta_outcome() {
+        assert_eq!(
+            "`[4, 4] + 2` = **10** → **Strong hit** 🥳",
+            outcome("+2", 1)
+        );
+        assert_eq!(
+            "`[4, 4] + 2` = **10** → **Weak hit**",
+            outcome("+2, 12/9", 1)
+        );
+        assert_eq!(
+            "`[5, 4, 1] - 1` = **8** → **Weak hit**",
+            outcome("-1, adv", 2)
+        );
+        assert_eq!(
+            "`[5, 4, 1] - 1` = **4** → **Miss** 🤬",
+            outcome("-1, dis", 2)
+        );
+    }
+}
@@ -0,0 +1,278 @@
+use std::collections::HashSet;
+
+use caith::{Critic, RollHistory, RollResult};
+
+use super::{syntax_error, with_outcome, GameSystem, Outcome};
+
+/// Shadowrun: d6 pool, 5 and 6 are hits, a glitch when more than half the dices are ones
+pub struct Shadowrun;
+
+/// World of Darkness: d10 pool against a difficulty, ones cancel successes
+pub struct Wod;
+
+/// Exalted: d10 pool, 7 and more are successes, 10s count twice
+pub struct Exalted;
+
+// The values of every dice rolled
+fn dices(res: &RollResult) -> Vec<u64> {
+    res.as_single()
+        .map(|res| {
+            res.get_history()
+                .iter()
+                .filter_map(|h| match h {
+                    RollHistory::Roll(dices) => Some(dices.iter().map(|d| d.res)),
+                    _ => None,
+                })
+                .flatten()
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+fn count(dices: &[u64], f: impl Fn(u64) -> bool) -> u64 {
+    dices.iter().filter(|d| f(**d)).count() as u64
+}
+
+fn successes(n: u64, one: &str, many: &str) -> String {
+    match n {
+        1 => format!("**1 {}**", one),
+        n => format!("**{} {}**", n, many),
+    }
+}
+
+// `pool[, value]`, the value has a default
+fn pool_params(system: &dyn GameSystem, params: &str, default: u64) -> Result<(u64, u64), String> {
+    let parse = |n: &str| n.trim().parse::<u64>().map_err(|_| syntax_error(system));
+    let (pool, value) = match params.split_once(',') {
+        Some((pool, value)) => (parse(pool)?, parse(value)?),
+        None => (parse(params)?, default),
+    };
+    if pool == 0 {
+        Err(syntax_error(system))
+    } else {
+        Ok((pool, value))
+    }
+}
+
+#[derive(Debug, PartialEq)]
+enum ShadowrunOutcome {
+    Hits(u64),
+    Glitch(u64),
+    CriticalGlitch,
+}
+
+impl ShadowrunOutcome {
+    fn new(dices: &[u64]) -> Self {
+        let hits = count(dices, |d| d >= 5);
+        let glitch = count(dices, |d| d == 1) * 2 > dices.len() as u64;
+        match (hits, glitch) {
+            (0, true) => ShadowrunOutcome::CriticalGlitch,
+            (hits, true) => ShadowrunOutcome::Glitch(hits),
+            (hits, false) => ShadowrunOutcome::Hits(hits),
+        }
+    }
+}
+
+impl GameSystem for Shadowrun {
+    fn name(&self) -> &'static str {
+        "sr"
+    }
+
+    fn syntax(&self) -> &'static str {
+        "sr(pool)"
+    }
+
+    fn description(&self) -> &'static str {
+        "Shadowrun: 5 and 6 are hits, a glitch when more than half the dices are ones, a critical \
+         glitch without any hit"
+    }
+
+    fn expression(&self, params: &str) -> Result<String, String> {
+        // the pool is the only parameter
+        if params.contains(',') {
+            return Err(syntax_error(self));
+        }
+        let (pool, _) = pool_params(self, params, 0)?;
+        Ok(format!("{}d6 t5", pool))
+    }
+
+    fn interpret(&self, _params: &str, res: RollResult) -> Result<Outcome, String> {
+        let outcome = match ShadowrunOutcome::new(&dices(&res)) {
+            ShadowrunOutcome::Hits(hits) => successes(hits, "hit", "hits"),
+            ShadowrunOutcome::Glitch(hits) => {
+                format!("{}, **Glitch** 🤬", successes(hits, "hit", "hits"))
+            }
+            ShadowrunOutcome::CriticalGlitch => "**Critical glitch** 🤬".to_string(),
+        };
+        Ok(Outcome::text(with_outcome(&res, &outcome)))
+    }
+
+    fn crit(&self, _params: &str, res: &RollResult) -> HashSet<Critic> {
+        let mut crit = HashSet::new();
+        if ShadowrunOutcome::new(&dices(res)) == ShadowrunOutcome::CriticalGlitch {
+            crit.insert(Critic::Min);
+        }
+        crit
+    }
+}
+
+// Successes left once the ones cancelled them, a botch when only ones are left
+fn wod_outcome(dices: &[u64], difficulty: u64) -> Result<u64, u64> {
+    let successes = count(dices, |d| d >= difficulty);
+    let ones = count(dices, |d| d == 1);
+    if successes == 0 && ones > 0 {
+        Err(ones)
+    } else {
+        Ok(successes.saturating_sub(ones))
+    }
+}
+
+impl GameSystem for Wod {
+    fn name(&self) -> &'static str {
+        "wod"
+    }
+
+    fn syntax(&self) -> &'static str {
+        "wod(pool[, difficulty])"
+    }
+
+    fn description(&self) -> &'static str {
+        "World of Darkness: successes on the difficulty (6 by default) or more, each one cancels a \
+         success, a botch when there are ones and no success"
+    }
+
+    fn expression(&self, params: &str) -> Result<String, String> {
+        let (pool, difficulty) = pool_params(self, params, 6)?;
+        if !(2..=10).contains(&difficulty) {
+            return Err("The difficulty is between 2 and 10".to_string());
+        }
+        Ok(format!("{}d10 t{} f1", pool, difficulty))
+    }
+
+    fn interpret(&self, params: &str, res: RollResult) -> Result<Outcome, String> {
+        let (_, difficulty) = pool_params(self, params, 6)?;
+        let outcome = match wod_outcome(&dices(&res), difficulty) {
+            Ok(0) => "**Failure**".to_string(),
+            Ok(n) => successes(n, "success", "successes"),
+            Err(ones) => format!("**Botch** 🤬 ({} ones)", ones),
+        };
+        Ok(Outcome::text(with_outcome(&res, &outcome)))
+    }
+
+    fn crit(&self, params: &str, res: &RollResult) -> HashSet<Critic> {
+        let mut crit = HashSet::new();
+        if let Ok((_, difficulty)) = pool_params(self, params, 6) {
+            if wod_outcome(&dices(res), difficulty).is_err() {
+                crit.insert(Critic::Min);
+            }
+        }
+        crit
+    }
+}
+
+// 10s count as two successes, a botch when there are ones and no success
+fn exalted_outcome(dices: &[u64], target: u64) -> Result<u64, u64> {
+    let successes = count(dices, |d| d >= target) + count(dices, |d| d == 10);
+    let ones = count(dices, |d| d == 1);
+    if successes == 0 && ones > 0 {
+        Err(ones)
+    } else {
+        Ok(successes)
+    }
+}
+
+impl GameSystem for Exalted {
+    fn name(&self) -> &'static str {
+        "ex"
+    }
+
+    fn syntax(&self) -> &'static str {
+        "ex(pool[, target])"
+    }
+
+    fn description(&self) -> &'static str {
+        "Exalted: successes on 7 (or the target) and more, 10s count twice, a botch when there are \
+         ones and no success"
+    }
+
+    fn expression(&self, params: &str) -> Result<String, String> {
+        let (pool, target) = pool_params(self, params, 7)?;
+        if !(2..=10).contains(&target) {
+            return Err("The target is between 2 and 10".to_string());
+        }
+        Ok(format!("{}d10 t{} tt10", pool, target))
+    }
+
+    fn interpret(&self, params: &str, res: RollResult) -> Result<Outcome, String> {
+        let (_, target) = pool_params(self, params, 7)?;
+        let outcome = match exalted_outcome(&dices(&res), target) {
+            Ok(0) => "**Failure**".to_string(),
+            Ok(n) => successes(n, "success", "successes"),
+            Err(ones) => format!("**Botch** 🤬 ({} ones)", ones),
+        };
+        Ok(Outcome::text(with_outcome(&res, &outcome)))
+    }
+
+    fn crit(&self, params: &str, res: &RollResult) -> HashSet<Critic> {
+        let mut crit = HashSet::new();
+        if let Ok((_, target)) = pool_params(self, params, 7) {
+            if exalted_outcome(&dices(res), target).is_err() {
+                crit.insert(Critic::Min);
+            }
+        }
+        crit
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::rng::rng_from_seed;
+
+    #[test]
+    fn failure_states() {
+        assert_eq!(
+            ShadowrunOutcome::Hits(2),
+            ShadowrunOutcome::new(&[5, 6, 1, 3])
+        );
+        assert_eq!(
+            ShadowrunOutcome::Glitch(1),
+            ShadowrunOutcome::new(&[5, 1, 1])
+        );
+        assert_eq!(
+            ShadowrunOutcome::CriticalGlitch,
+            ShadowrunOutcome::new(&[1, 1, 1, 4])
+        );
+
+        assert_eq!(Ok(1), wod_outcome(&[6, 8, 1, 3], 6));
+        assert_eq!(Ok(0), wod_outcome(&[6, 1, 1], 6));
+        assert_eq!(Err(2), wod_outcome(&[5, 1, 1], 6));
+
+        assert_eq!(Ok(3), exalted_outcome(&[10, 7, 1], 7));
+        assert_eq!(Err(1), exalted_outcome(&[6, 1], 7));
+    }
+
+    #[test]
+    fn pools_rolls() {
+        let roll = |system: &dyn GameSystem, params: &str| {
+            let roller = caith::Roller::new(&system.expression(params).unwrap()).unwrap();
+            let res = roller.roll_with(&mut rng_from_seed(3)).unwrap();
+            system.interpret(params, res).unwrap().text
+        };
+        assert_eq!(
+            "`[6, 5, 5, 4, 1, 1]` = **3** → **3 hits**",
+            roll(&Shadowrun, "6")
+        );
+        assert_eq!(
+            "`[8, 8, 7, 1, 1]` = **1** → **1 success**",
+            roll(&Wod, "5, 7")
+        );
+        assert_eq!(
+            "`[8, 8, 7, 6, 1, 1]` = **3** → **3 successes**",
+            roll(&Exalted, "6")
+        );
+        assert!(Wod.expression("5, 11").is_err());
+        assert!(Shadowrun.expression("0").is_err());
+        assert!(Shadowrun.expression("5, 3").is_err());
+    }
+}
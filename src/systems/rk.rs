@@ -0,0 +1,225 @@
+use caith::{RollHistory, RollResult};
+
+use super::{syntax_error, GameSystem, Outcome};
+
+/// Roll and keep (Legend of the Five Rings, 7th Sea): roll X d10, keep the Y highest, 10s explode
+pub struct Rk;
+
+// The dice limit of the ten dice rule
+const MAX_DICES: u32 = 10;
+
+#[derive(Debug, PartialEq)]
+struct RkParams {
+    rolled: u32,
+    kept: u32,
+    bonus: i64,
+    tn: Option<i64>,
+}
+
+impl Rk {
+    // `XkY[, +bonus][, tnN]`
+    fn params(&self, params: &str) -> Result<RkParams, String> {
+        let mut params = params.split(',').map(str::trim);
+        let (rolled, kept) = params
+            .next()
+            .and_then(|dices| {
+                dices
+                    .to_lowercase()
+                    .split_once('k')
+                    .map(|(r, k)| (r.parse(), k.parse()))
+            })
+            .and_then(|(rolled, kept)| Some((rolled.ok()?, kept.ok()?)))
+            .ok_or_else(|| syntax_error(self))?;
+        if rolled == 0 || kept == 0 || kept > rolled {
+            return Err("Keep between 1 and the number of rolled dices".to_string());
+        }
+        let mut rk = RkParams {
+            rolled,
+            kept,
+            bonus: 0,
+            tn: None,
+        };
+        for param in params {
+            let param = param.to_lowercase();
+            match param.strip_prefix("tn") {
+                Some(tn) => rk.tn = Some(tn.trim().parse().map_err(|_| syntax_error(self))?),
+                None => {
+                    rk.bonus = param
+                        .trim_start_matches('+')
+                        .parse()
+                        .map_err(|_| syntax_error(self))?
+                }
+            }
+        }
+        Ok(rk)
+    }
+}
+
+impl RkParams {
+    /// Ten dice rule: rolled dices beyond 10 become kept dices, two for one, then kept dices
+    /// beyond 10 become a +2 bonus each
+    fn ten_dice_rule(&self) -> RkParams {
+        let mut kept = self.kept;
+        let mut rolled = self.rolled;
+        if rolled > MAX_DICES {
+            kept += (rolled - MAX_DICES) / 2;
+            rolled = MAX_DICES;
+        }
+        let mut bonus = self.bonus;
+        if kept > MAX_DICES {
+            bonus += 2 * (kept - MAX_DICES) as i64;
+            kept = MAX_DICES;
+        }
+        RkParams {
+            rolled,
+            kept,
+            bonus,
+            tn: self.tn,
+        }
+    }
+}
+
+// The value of each dice once its 10s exploded: caith rolls every explosion in a new group, one
+// dice for each 10 of the previous group
+fn exploded_dices(groups: &[Vec<u64>]) -> Vec<u64> {
+    let mut dices = match groups.first() {
+        Some(first) => first.clone(),
+        None => return Vec::new(),
+    };
+    let mut exploding: Vec<usize> = (0..dices.len()).filter(|i| dices[*i] == 10).collect();
+    for group in &groups[1..] {
+        let mut next = Vec::new();
+        for (idx, value) in exploding.iter().zip(group) {
+            dices[*idx] += value;
+            if *value == 10 {
+                next.push(*idx);
+            }
+        }
+        exploding = next;
+    }
+    dices
+}
+
+fn rk_outcome(params: &RkParams, mut dices: Vec<u64>) -> String {
+    dices.sort_unstable_by(|a, b| b.cmp(a));
+    let (kept, dropped) = dices.split_at((params.kept as usize).min(dices.len()));
+    let total = kept.iter().sum::<u64>() as i64 + params.bonus;
+    let join = |dices: &[u64]| -> String {
+        let dices: Vec<String> = dices.iter().map(u64::to_string).collect();
+        dices.join(", ")
+    };
+    let mut s = if dropped.is_empty() {
+        format!("`[{}]`", join(kept))
+    } else {
+        format!("`[{} | {}]`", join(kept), join(dropped))
+    };
+    if params.bonus > 0 {
+        s.push_str(&format!(" + {}", params.bonus));
+    } else if params.bonus < 0 {
+        s.push_str(&format!(" - {}", -params.bonus));
+    }
+    s.push_str(&format!(" = **{}**", total));
+    if let Some(tn) = params.tn {
+        match (total - tn) / 5 {
+            _ if total < tn => s.push_str(&format!(" vs TN {} → **Failure**", tn)),
+            0 => s.push_str(&format!(" vs TN {} → **Success**", tn)),
+            1 => s.push_str(&format!(" vs TN {} → **Success** with 1 raise", tn)),
+            raises => s.push_str(&format!(
+                " vs TN {} → **Success** with {} raises",
+                tn, raises
+            )),
+        }
+    }
+    s
+}
+
+impl GameSystem for Rk {
+    fn name(&self) -> &'static str {
+        "rk"
+    }
+
+    fn syntax(&self) -> &'static str {
+        "rk(XkY[, +bonus][, tnN])"
+    }
+
+    fn description(&self) -> &'static str {
+        "Roll and keep games like Legend of the Five Rings: roll X d10 and keep the Y highest, 10s \
+         explode. Rolled dices beyond 10 become kept dices (two for one), kept dices beyond 10 \
+         give +2 each. With a TN, every 5 above it is a raise"
+    }
+
+    fn expression(&self, params: &str) -> Result<String, String> {
+        let params = self.params(params)?.ten_dice_rule();
+        Ok(format!("{}d10 ie10", params.rolled))
+    }
+
+    fn interpret(&self, params: &str, res: RollResult) -> Result<Outcome, String> {
+        let asked = self.params(params)?;
+        let params = asked.ten_dice_rule();
+        let groups: Vec<Vec<u64>> = res
+            .as_single()
+            .ok_or_else(|| syntax_error(self))?
+            .get_history()
+            .iter()
+            .filter_map(|h| match h {
+                RollHistory::Roll(dices) => Some(dices.iter().map(|d| d.res).collect()),
+                _ => None,
+            })
+            .collect();
+        let mut text = rk_outcome(&params, exploded_dices(&groups));
+        if params != asked {
+            text = format!("{}k{}: {}", params.rolled, params.kept, text);
+        }
+        if let Some(reason) = res.get_reason() {
+            text.push_str(&format!(", Reason: `{}`", reason));
+        }
+        Ok(Outcome::text(text))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::rng::rng_from_seed;
+
+    #[test]
+    fn ten_dice_rule() {
+        let rk = |params: &str| {
+            let params = Rk.params(params).unwrap().ten_dice_rule();
+            (params.rolled, params.kept, params.bonus)
+        };
+        assert_eq!((7, 3, 5), rk("7k3, +5"));
+        assert_eq!((10, 5, 0), rk("12K4"));
+        assert_eq!((10, 10, 4), rk("14k10"));
+        assert_eq!((10, 10, 1), rk("13k10, -1"));
+        assert!(Rk.params("3k4").is_err());
+        assert!(Rk.params("7k3, tn").is_err());
+    }
+
+    #[test]
+    fn tens_explode() {
+        assert_eq!(
+            vec![17, 4, 30, 2],
+            exploded_dices(&[vec![10, 4, 10, 2], vec![7, 10], vec![10], vec![0]])
+        );
+        assert_eq!(vec![5, 3], exploded_dices(&[vec![5, 3]]));
+    }
+
+    #[test]
+    fn raises_against_tn() {
+        let params = Rk.params("4k2, +3, tn15").unwrap();
+        assert_eq!(
+            "`[17, 8 | 6, 1]` + 3 = **28** vs TN 15 → **Success** with 2 raises",
+            rk_outcome(&params, vec![8, 1, 17, 6])
+        );
+        assert_eq!(
+            "`[6, 1]` + 3 = **10** vs TN 15 → **Failure**",
+            rk_outcome(&params, vec![6, 1])
+        );
+        let res = caith::Roller::new(&Rk.expression("12k4, tn20").unwrap())
+            .unwrap()
+            .roll_with(&mut rng_from_seed(5))
+            .unwrap();
+        assert_eq!("10k5: `[13, 9, 9, 9, 8 | 8, 7, 7, 7, 6]` = **48** vs TN 20 → **Success** with 5 raises", Rk.interpret("12k4, tn20", res).unwrap().text);
+    }
+}
@@ -0,0 +1,209 @@
+use std::collections::HashSet;
+
+use caith::{Critic, RollHistory, RollResult};
+
+use super::{syntax_error, GameSystem, Outcome};
+
+/// Savage Worlds trait roll: the trait dice and a d6 wild dice, both exploding, the best is kept
+pub struct Sw;
+
+const WILD_DICE: u64 = 6;
+const DEFAULT_TN: i64 = 4;
+// Every 4 above the TN is a raise
+const RAISE: i64 = 4;
+
+#[derive(Debug, PartialEq)]
+struct SwParams {
+    sides: u64,
+    modifier: i64,
+    tn: i64,
+}
+
+impl Sw {
+    // `dX[, +modifier][, tnN]`
+    fn params(&self, params: &str) -> Result<SwParams, String> {
+        let mut params = params.split(',').map(str::trim);
+        let sides = match params
+            .next()
+            .and_then(|dice| dice.strip_prefix(|c| c == 'd' || c == 'D'))
+            .map(str::parse)
+        {
+            Some(Ok(sides)) if [4, 6, 8, 10, 12].contains(&sides) => sides,
+            _ => return Err("The trait dice is a d4, d6, d8, d10 or d12".to_string()),
+        };
+        let mut sw = SwParams {
+            sides,
+            modifier: 0,
+            tn: DEFAULT_TN,
+        };
+        for param in params {
+            let param = param.to_lowercase();
+            match param.strip_prefix("tn") {
+                Some(tn) => sw.tn = tn.trim().parse().map_err(|_| syntax_error(self))?,
+                None => {
+                    sw.modifier = param
+                        .trim_start_matches('+')
+                        .parse()
+                        .map_err(|_| syntax_error(self))?
+                }
+            }
+        }
+        Ok(sw)
+    }
+}
+
+// The rolls of an exploding dice: the first one, then one more for each max value.
+// Returns the rolls and the groups left.
+fn explosion(groups: &[Vec<u64>], sides: u64) -> (Vec<u64>, &[Vec<u64>]) {
+    let mut rolls = Vec::new();
+    let mut groups = groups;
+    while let Some((group, rest)) = groups.split_first() {
+        rolls.extend(group);
+        groups = rest;
+        if group.last() != Some(&sides) {
+            break;
+        }
+    }
+    (rolls, groups)
+}
+
+fn sw_outcome(params: &SwParams, trait_dice: &[u64], wild_dice: &[u64]) -> String {
+    let total = |rolls: &[u64]| rolls.iter().sum::<u64>() as i64 + params.modifier;
+    let (trait_total, wild_total) = (total(trait_dice), total(wild_dice));
+    let mut s = format!(
+        "Trait `{:?}` = {}, Wild `{:?}` = {}",
+        trait_dice, trait_total, wild_dice, wild_total
+    );
+    let best = trait_total.max(wild_total);
+    if trait_dice.first() == Some(&1) && wild_dice.first() == Some(&1) {
+        s.push_str(" → **Critical failure** 🤬");
+        return s;
+    }
+    s.push_str(&format!(" → **{}** vs TN {}: ", best, params.tn));
+    match (best - params.tn) / RAISE {
+        _ if best < params.tn => s.push_str("**Failure**"),
+        0 => s.push_str("**Success**"),
+        1 => s.push_str("**Success** with 1 raise"),
+        raises => s.push_str(&format!("**Success** with {} raises", raises)),
+    }
+    s
+}
+
+impl Sw {
+    // The rolls of the trait dice and of the wild dice
+    fn dices(&self, params: &SwParams, res: &RollResult) -> Result<(Vec<u64>, Vec<u64>), String> {
+        let groups: Vec<Vec<u64>> = res
+            .as_single()
+            .ok_or_else(|| syntax_error(self))?
+            .get_history()
+            .iter()
+            .filter_map(|h| match h {
+                RollHistory::Roll(dices) => Some(dices.iter().map(|d| d.res).collect()),
+                _ => None,
+            })
+            .collect();
+        let (trait_dice, groups) = explosion(&groups, params.sides);
+        let (wild_dice, _) = explosion(groups, WILD_DICE);
+        if trait_dice.is_empty() || wild_dice.is_empty() {
+            Err(syntax_error(self))
+        } else {
+            Ok((trait_dice, wild_dice))
+        }
+    }
+}
+
+impl GameSystem for Sw {
+    fn name(&self) -> &'static str {
+        "sw"
+    }
+
+    fn syntax(&self) -> &'static str {
+        "sw(dX[, +modifier][, tnN])"
+    }
+
+    fn description(&self) -> &'static str {
+        "Savage Worlds trait roll: the trait dice and a d6 wild dice explode, the best is kept. \
+         Success against TN 4 (or the given TN) and a raise every 4 above, critical failure on \
+         double ones"
+    }
+
+    fn expression(&self, params: &str) -> Result<String, String> {
+        let params = self.params(params)?;
+        Ok(format!("1d{} ie + 1d{} ie", params.sides, WILD_DICE))
+    }
+
+    fn interpret(&self, params: &str, res: RollResult) -> Result<Outcome, String> {
+        let params = self.params(params)?;
+        let (trait_dice, wild_dice) = self.dices(&params, &res)?;
+        let mut text = sw_outcome(&params, &trait_dice, &wild_dice);
+        if let Some(reason) = res.get_reason() {
+            text.push_str(&format!(", Reason: `{}`", reason));
+        }
+        Ok(Outcome::text(text))
+    }
+
+    fn crit(&self, params: &str, res: &RollResult) -> HashSet<Critic> {
+        let mut crit = HashSet::new();
+        if let Ok(params) = self.params(params) {
+            if let Ok((trait_dice, wild_dice)) = self.dices(&params, res) {
+                if trait_dice[0] == 1 && wild_dice[0] == 1 {
+                    crit.insert(Critic::Min);
+                }
+            }
+        }
+        crit
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::rng::rng_from_seed;
+
+    #[test]
+    fn sw_params() {
+        assert_eq!(
+            Ok(SwParams {
+                sides: 8,
+                modifier: 1,
+                tn: 6
+            }),
+            Sw.params("d8, +1, tn6")
+        );
+        assert!(Sw.params("d7").is_err());
+        assert!(Sw.params("8").is_err());
+        assert_eq!(Ok("1d8 ie + 1d6 ie".to_string()), Sw.expression("d8"));
+    }
+
+    #[test]
+    fn exploding_trait_and_wild_dices() {
+        let groups = vec![vec![8], vec![8], vec![3], vec![6], vec![2]];
+        let (trait_dice, groups) = explosion(&groups, 8);
+        assert_eq!(vec![8, 8, 3], trait_dice);
+        assert_eq!(vec![6, 2], explosion(groups, 6).0);
+
+        let params = Sw.params("d8, -1").unwrap();
+        assert_eq!(
+            "Trait `[8, 8, 3]` = 18, Wild `[6, 2]` = 7 → **18** vs TN 4: **Success** with 3 raises",
+            sw_outcome(&params, &[8, 8, 3], &[6, 2])
+        );
+        assert_eq!(
+            "Trait `[1]` = 0, Wild `[1]` = 0 → **Critical failure** 🤬",
+            sw_outcome(&params, &[1], &[1])
+        );
+        assert_eq!(
+            "Trait `[3]` = 2, Wild `[2]` = 1 → **2** vs TN 4: **Failure**",
+            sw_outcome(&params, &[3], &[2])
+        );
+    }
+
+    #[test]
+    fn sw_roll() {
+        let roller = caith::Roller::new(&Sw.expression("d6").unwrap()).unwrap();
+        let res = roller.roll_with(&mut rng_from_seed(1)).unwrap();
+        assert_eq!(
+            "Trait `[4]` = 4, Wild `[4]` = 4 → **4** vs TN 4: **Success**",
+            Sw.interpret("d6", res).unwrap().text
+        );
+    }
+}
@@ -0,0 +1,274 @@
+use std::fmt::Display;
+
+use caith::{RollHistory, RollResult};
+
+use super::{parse_call, syntax_error, GameSystem, Outcome};
+
+/// Year Zero Engine (Mutant: Year Zero, Forbidden Lands, Alien…): pools of d6, sixes are successes
+pub struct Yze;
+
+// Number of dices of the base, skill and gear pools
+struct Pools([u32; 3]);
+
+const POOL_NAMES: [&str; 3] = ["Base", "Skill", "Gear"];
+
+impl Yze {
+    // `base[, skill[, gear]]`
+    fn pools(&self, params: &str) -> Result<Pools, String> {
+        let mut pools = [0; 3];
+        let mut params = params.split(',');
+        for pool in pools.iter_mut() {
+            if let Some(dices) = params.next() {
+                *pool = dices.trim().parse().map_err(|_| syntax_error(self))?;
+            }
+        }
+        if params.next().is_some() || pools.iter().all(|dices| *dices == 0) {
+            return Err(syntax_error(self));
+        }
+        Ok(Pools(pools))
+    }
+}
+
+/// The dices of a Year Zero roll by pool, kept to be pushed
+#[derive(Clone, Debug, PartialEq)]
+pub struct YzeRoll {
+    // base, skill and gear dices
+    pools: [Vec<u64>; 3],
+    pushed: bool,
+}
+
+impl YzeRoll {
+    fn new(pools: &Pools, res: &RollResult) -> Option<Self> {
+        let mut rolls = res
+            .as_single()?
+            .get_history()
+            .iter()
+            .filter_map(|h| match h {
+                RollHistory::Roll(dices) => Some(dices.iter().map(|d| d.res).collect()),
+                _ => None,
+            });
+        let mut dices: [Vec<u64>; 3] = Default::default();
+        for (pool, count) in dices.iter_mut().zip(pools.0.iter()) {
+            if *count > 0 {
+                *pool = rolls.next()?;
+            }
+        }
+        Some(YzeRoll {
+            pools: dices,
+            pushed: false,
+        })
+    }
+
+    /// The roll of a `yze` call, `None` if the call is not a Year Zero roll
+    pub fn from_call(call: &str, res: &RollResult) -> Option<Self> {
+        match parse_call(call) {
+            Some((system, params)) if system.name() == Yze.name() => {
+                YzeRoll::new(&Yze.pools(params).ok()?, res)
+            }
+            _ => None,
+        }
+    }
+
+    // Sixes are kept, as well as the ones of the base and gear dices
+    fn is_kept(pool: usize, dice: u64) -> bool {
+        dice == 6 || (dice == 1 && pool != 1)
+    }
+
+    // Number of dices of each pool rolled again by a push
+    fn to_push(&self) -> impl Iterator<Item = usize> + '_ {
+        self.pools
+            .iter()
+            .enumerate()
+            .map(|(pool, dices)| dices.iter().filter(|d| !Self::is_kept(pool, **d)).count())
+    }
+
+    /// Expression rolling again the dices which are not kept, one group per pool.
+    /// Ex: `2d6 + 1d6` when two base dices and one skill dice are pushed
+    pub fn push_expression(&self) -> Result<String, String> {
+        if self.pushed {
+            return Err("A roll can only be pushed once".to_string());
+        }
+        let groups: Vec<String> = self
+            .to_push()
+            .filter(|count| *count > 0)
+            .map(|count| format!("{}d6", count))
+            .collect();
+        if groups.is_empty() {
+            Err("No dice to push".to_string())
+        } else {
+            Ok(groups.join(" + "))
+        }
+    }
+
+    /// Replace the dices which are not kept by the ones of the roll of `push_expression`, each
+    /// pool taking the dices of its own group. `None` if the roll doesn't match the pools.
+    pub fn push(&mut self, res: &RollResult) -> Option<()> {
+        let mut groups = res
+            .as_single()?
+            .get_history()
+            .iter()
+            .filter_map(|h| match h {
+                RollHistory::Roll(dices) => Some(dices),
+                _ => None,
+            });
+        let mut pools = self.pools.clone();
+        for ((pool, dices), count) in pools.iter_mut().enumerate().zip(self.to_push()) {
+            if count == 0 {
+                continue;
+            }
+            let group = groups.next().filter(|group| group.len() == count)?;
+            let pushed = dices.iter_mut().filter(|d| !Self::is_kept(pool, **d));
+            for (dice, rerolled) in pushed.zip(group.iter()) {
+                *dice = rerolled.res;
+            }
+        }
+        self.pools = pools;
+        self.pushed = true;
+        Some(())
+    }
+
+    fn successes(&self) -> usize {
+        self.pools.iter().flatten().filter(|d| **d == 6).count()
+    }
+
+    fn banes(&self, pool: usize) -> usize {
+        self.pools[pool].iter().filter(|d| **d == 1).count()
+    }
+
+    /// What a pushed roll costs: damage or stress for the base banes, gear bonus for the gear ones
+    pub fn consequences(&self) -> String {
+        let (base, gear) = (self.banes(0), self.banes(2));
+        if base == 0 && gear == 0 {
+            return "No bane, the push costs nothing".to_string();
+        }
+        let mut consequences = Vec::new();
+        if base > 0 {
+            consequences.push(format!("{} damage or stress to the attribute", base));
+        }
+        if gear > 0 {
+            consequences.push(format!("gear bonus -{}", gear));
+        }
+        consequences.join(", ")
+    }
+}
+
+impl Display for YzeRoll {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let pools: Vec<String> = POOL_NAMES
+            .iter()
+            .zip(self.pools.iter())
+            .filter(|(_, dices)| !dices.is_empty())
+            .map(|(name, dices)| {
+                let dices: Vec<String> = dices.iter().map(u64::to_string).collect();
+                format!("{} `[{}]`", name, dices.join(", "))
+            })
+            .collect();
+        write!(f, "{} → ", pools.join(" "))?;
+        match self.successes() {
+            0 => write!(f, "**No success** 🤬")?,
+            1 => write!(f, "**1 success**")?,
+            n => write!(f, "**{} successes**", n)?,
+        }
+        let banes = self.banes(0) + self.banes(2);
+        if banes > 0 {
+            write!(
+                f,
+                ", {} {}",
+                banes,
+                if banes > 1 { "banes" } else { "bane" }
+            )?;
+        }
+        Ok(())
+    }
+}
+
+impl GameSystem for Yze {
+    fn name(&self) -> &'static str {
+        "yze"
+    }
+
+    fn syntax(&self) -> &'static str {
+        "yze(base[, skill[, gear]])"
+    }
+
+    fn description(&self) -> &'static str {
+        "Year Zero Engine games like Mutant: Year Zero or Forbidden Lands: sixes are successes, \
+         ones on base and gear dices are banes. Push the roll with `/push`"
+    }
+
+    fn expression(&self, params: &str) -> Result<String, String> {
+        let pools: Vec<String> = self
+            .pools(params)?
+            .0
+            .iter()
+            .filter(|dices| **dices > 0)
+            .map(|dices| format!("{}d6", dices))
+            .collect();
+        Ok(pools.join(" + "))
+    }
+
+    fn interpret(&self, params: &str, res: RollResult) -> Result<Outcome, String> {
+        let roll = YzeRoll::new(&self.pools(params)?, &res).ok_or_else(|| syntax_error(self))?;
+        let mut text = roll.to_string();
+        if let Some(reason) = res.get_reason() {
+            text.push_str(&format!(", Reason: `{}`", reason));
+        }
+        Ok(Outcome::text(text))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::rng::rng_from_seed;
+
+    #[test]
+    fn yze_expression() {
+        assert_eq!(Ok("3d6 + 2d6 + 1d6".to_string()), Yze.expression("3, 2, 1"));
+        assert_eq!(Ok("4d6 + 1d6".to_string()), Yze.expression("4, 0, 1"));
+        assert_eq!(Ok("2d6".to_string()), Yze.expression("2"));
+        assert!(Yze.expression("0, 0").is_err());
+        assert!(Yze.expression("1, 2, 3, 4").is_err());
+    }
+
+    #[test]
+    fn yze_roll_from_call() {
+        let roller = caith::Roller::new(&Yze.expression("2, 0, 1").unwrap()).unwrap();
+        let res = roller.roll_with(&mut rng_from_seed(3)).unwrap();
+        let roll = YzeRoll::from_call("yze(2, 0, 1)", &res).unwrap();
+        assert_eq!(
+            "Base `[4, 1]` Gear `[1]` → **No success** 🤬, 2 banes",
+            roll.to_string()
+        );
+        assert!(YzeRoll::from_call("fitd(2, 0, 1)", &res).is_none());
+    }
+
+    #[test]
+    fn push_yze_roll() {
+        let mut rng = rng_from_seed(7);
+        let roller = caith::Roller::new(&Yze.expression("3, 2, 1").unwrap()).unwrap();
+        let res = roller.roll_with(&mut rng).unwrap();
+        let mut roll = YzeRoll::from_call("yze(3, 2, 1)", &res).unwrap();
+        assert_eq!(
+            "Base `[6, 4, 1]` Skill `[2, 2]` Gear `[3]` → **1 success**, 1 bane",
+            roll.to_string()
+        );
+        // the six and the base bane are kept, each pool is pushed by its own group
+        let input = roll.push_expression().unwrap();
+        assert_eq!("1d6 + 2d6 + 1d6", input);
+        let pushed = caith::Roller::new(&input)
+            .unwrap()
+            .roll_with(&mut rng)
+            .unwrap();
+        assert_eq!("`[5] + [6, 3] + [4]` = **18**", pushed.to_string());
+        // a roll which doesn't match the pools is refused
+        assert_eq!(None, roll.clone().push(&res));
+        assert_eq!(Some(()), roll.push(&pushed));
+        assert_eq!(
+            "Base `[6, 5, 1]` Skill `[6, 3]` Gear `[4]` → **2 successes**, 1 bane",
+            roll.to_string()
+        );
+        assert_eq!("1 damage or stress to the attribute", roll.consequences());
+        assert!(roll.push_expression().is_err());
+    }
+}